@@ -0,0 +1,249 @@
+//! Angle wrapping utilities
+//!
+//! Rust's `%` operator keeps the sign of the dividend, so manual
+//! wrapping with `x % TAU` returns negative angles for negative input.
+//! These functions always return a value in the documented range.
+//!
+//! `Angle` wraps a value in radians so that the unit travels with it.
+
+use std::f64::consts::{PI, TAU};
+
+/// Wrap `x` into the half-open range [0, period)
+fn wrap_positive(x: f64, period: f64) -> f64 {
+    let r = x.rem_euclid(period);
+    // rem_euclid can round up to exactly `period` for tiny negative input
+    if r >= period {
+        0.0
+    } else {
+        r
+    }
+}
+
+/// Wrap `x` into the half-open range (-half, half]
+fn wrap_centered(x: f64, half: f64) -> f64 {
+    let r = wrap_positive(x, 2.0 * half);
+    if r > half {
+        r - 2.0 * half
+    } else {
+        r
+    }
+}
+
+/// Wrap an angle in radians to the range [0, 2π)
+///
+/// # Arguments
+/// * `x` - Angle in radians
+///
+/// # Returns
+/// The equivalent angle in [0, 2π)
+///
+/// # Example
+/// ```
+/// use satctrl::wrap_2pi;
+/// assert!((wrap_2pi(-std::f64::consts::FRAC_PI_2) - 1.5 * std::f64::consts::PI).abs() < 1.0e-15);
+/// ```
+///
+pub fn wrap_2pi(x: f64) -> f64 {
+    wrap_positive(x, TAU)
+}
+
+/// Wrap an angle in radians to the range (-π, π]
+///
+/// # Arguments
+/// * `x` - Angle in radians
+///
+/// # Returns
+/// The equivalent angle in (-π, π]
+///
+/// # Example
+/// ```
+/// use satctrl::wrap_pi;
+/// assert_eq!(wrap_pi(-std::f64::consts::PI), std::f64::consts::PI);
+/// ```
+///
+pub fn wrap_pi(x: f64) -> f64 {
+    wrap_centered(x, PI)
+}
+
+/// Wrap an angle in degrees to the range [0, 360)
+///
+/// # Arguments
+/// * `x` - Angle in degrees
+///
+/// # Returns
+/// The equivalent angle in [0, 360)
+///
+pub fn wrap_360(x: f64) -> f64 {
+    wrap_positive(x, 360.0)
+}
+
+/// Wrap an angle in degrees to the range (-180, 180]
+///
+/// # Arguments
+/// * `x` - Angle in degrees
+///
+/// # Returns
+/// The equivalent angle in (-180, 180]
+///
+pub fn wrap_180(x: f64) -> f64 {
+    wrap_centered(x, 180.0)
+}
+
+/// Plane angle, stored in radians
+///
+/// Construct from either unit and read back in either unit; the wrapping
+/// methods return a new `Angle` in the stated range. Angles add, subtract,
+/// negate, and scale by `f64`.
+///
+/// # Example
+/// ```
+/// use satctrl::Angle;
+/// let raan = Angle::from_degrees(-90.0);
+/// assert!((raan.wrapped_2pi().degrees() - 270.0).abs() < 1.0e-12);
+/// assert!((raan.radians() + std::f64::consts::FRAC_PI_2).abs() < 1.0e-15);
+/// ```
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Angle(f64);
+
+impl Angle {
+    /// Angle from a value in radians
+    pub const fn from_radians(rad: f64) -> Self {
+        Self(rad)
+    }
+
+    /// Angle from a value in degrees
+    pub fn from_degrees(deg: f64) -> Self {
+        Self(deg.to_radians())
+    }
+
+    /// Value in radians
+    pub const fn radians(&self) -> f64 {
+        self.0
+    }
+
+    /// Value in degrees
+    pub fn degrees(&self) -> f64 {
+        self.0.to_degrees()
+    }
+
+    /// Equivalent angle in (-π, π]; see `wrap_pi`
+    pub fn wrapped_pi(&self) -> Self {
+        Self(wrap_pi(self.0))
+    }
+
+    /// Equivalent angle in [0, 2π); see `wrap_2pi`
+    pub fn wrapped_2pi(&self) -> Self {
+        Self(wrap_2pi(self.0))
+    }
+
+    /// Sine of the angle
+    pub fn sin(&self) -> f64 {
+        self.0.sin()
+    }
+
+    /// Cosine of the angle
+    pub fn cos(&self) -> f64 {
+        self.0.cos()
+    }
+}
+
+impl std::ops::Add for Angle {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl std::ops::Sub for Angle {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0)
+    }
+}
+
+impl std::ops::Neg for Angle {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl std::ops::Mul<f64> for Angle {
+    type Output = Self;
+    fn mul(self, other: f64) -> Self {
+        Self(self.0 * other)
+    }
+}
+
+impl std::ops::Div<f64> for Angle {
+    type Output = Self;
+    fn div(self, other: f64) -> Self {
+        Self(self.0 / other)
+    }
+}
+
+impl std::fmt::Display for Angle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match f.precision() {
+            Some(p) => write!(f, "{:.*}°", p, self.degrees()),
+            None => write!(f, "{}°", self.degrees()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_radians() {
+        assert!((wrap_2pi(-0.5) - (TAU - 0.5)).abs() < 1.0e-15);
+        assert!((wrap_2pi(100.0 * TAU + 1.0) - 1.0).abs() < 1.0e-12);
+        assert!((wrap_2pi(-100.0 * TAU - 1.0) - (TAU - 1.0)).abs() < 1.0e-12);
+        assert_eq!(wrap_2pi(TAU), 0.0);
+        let tiny = wrap_2pi(-1.0e-20);
+        assert!((0.0..TAU).contains(&tiny));
+
+        assert_eq!(wrap_pi(PI), PI);
+        assert_eq!(wrap_pi(-PI), PI);
+        assert!((wrap_pi(1.5 * PI) + 0.5 * PI).abs() < 1.0e-15);
+        assert!((wrap_pi(-7.0 * TAU - 0.25) + 0.25).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn test_wrap_degrees() {
+        assert_eq!(wrap_360(-90.0), 270.0);
+        assert_eq!(wrap_360(720.0 + 45.0), 45.0);
+        assert_eq!(wrap_360(-3600.0 - 10.0), 350.0);
+        assert_eq!(wrap_180(180.0), 180.0);
+        assert_eq!(wrap_180(-180.0), 180.0);
+        assert_eq!(wrap_180(270.0), -90.0);
+        assert_eq!(wrap_180(-1090.0), -10.0);
+    }
+
+    #[test]
+    fn test_angle() {
+        let a = Angle::from_degrees(180.0);
+        assert_eq!(a.radians(), PI);
+        assert_eq!(Angle::from_radians(PI).degrees(), 180.0);
+        assert!((Angle::from_degrees(30.0).sin() - 0.5).abs() < 1.0e-15);
+        assert!((Angle::from_degrees(60.0).cos() - 0.5).abs() < 1.0e-15);
+
+        // Wrapping
+        assert_eq!(Angle::from_radians(-PI).wrapped_pi().radians(), PI);
+        assert_eq!(Angle::from_radians(TAU).wrapped_2pi().radians(), 0.0);
+        let az = Angle::from_degrees(-450.0);
+        assert!((az.wrapped_2pi().degrees() - 270.0).abs() < 1.0e-12);
+        assert!((az.wrapped_pi().degrees() + 90.0).abs() < 1.0e-12);
+
+        // Arithmetic
+        let b = Angle::from_degrees(350.0) + Angle::from_degrees(20.0);
+        assert!((b.wrapped_2pi().degrees() - 10.0).abs() < 1.0e-12);
+        let c = (Angle::from_degrees(10.0) - Angle::from_degrees(30.0)) * 2.0;
+        assert!((c.degrees() + 40.0).abs() < 1.0e-12);
+        assert!(((-c / 4.0).degrees() - 10.0).abs() < 1.0e-12);
+        assert!(Angle::from_degrees(1.0) < Angle::from_degrees(2.0));
+        assert_eq!(format!("{:.1}", Angle::from_degrees(45.0)), "45.0°");
+    }
+}
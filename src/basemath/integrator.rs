@@ -0,0 +1,189 @@
+use super::{rk45_integrate, rk4_integrate, rk8_integrate};
+use super::{IntegrationStats, ODEState, RKAdaptiveSettings};
+use crate::{SCError, SCResult};
+
+/// Integration methods available to the `Integrator` builder
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntegrationMethod {
+    /// Fixed-step 4th-order Runge-Kutta
+    RK4,
+    /// Fixed-step 8th-order Runge-Kutta (Cooper-Verner)
+    RK8,
+    /// Adaptive Dormand-Prince 5(4)
+    RK45,
+}
+
+/// Builder-style configuration for the Runge-Kutta integrators
+///
+/// Adaptive methods use the tolerances and step size limits. Fixed-step
+/// methods take steps of `max_step`, shortening the last one to end
+/// exactly on the final time, and ignore the tolerances.
+///
+/// # Example
+///
+/// ```
+/// use satctrl::{IntegrationMethod, Integrator, Vector2};
+/// let f = |_x: f64, y: &Vector2| Vector2::from_vec([y[1], -y[0]]);
+/// let y0 = Vector2::from_vec([1.0, 0.0]);
+/// let (y, stats) = Integrator::new()
+///     .method(IntegrationMethod::RK45)
+///     .rtol(1.0e-10)
+///     .atol(1.0e-10)
+///     .max_step(0.5)
+///     .integrate(f, y0, 0.0, 1.0)
+///     .unwrap();
+/// assert!((y[0] - 1.0_f64.cos()).abs() < 1.0e-9);
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct Integrator {
+    method: IntegrationMethod,
+    settings: RKAdaptiveSettings,
+}
+
+impl Default for Integrator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Integrator {
+    /// Create a new integrator using `IntegrationMethod::RK45` and the
+    /// default `RKAdaptiveSettings`
+    pub fn new() -> Self {
+        Self {
+            method: IntegrationMethod::RK45,
+            settings: RKAdaptiveSettings::default(),
+        }
+    }
+
+    /// Set the integration method
+    pub fn method(mut self, method: IntegrationMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Set the relative tolerance
+    pub fn rtol(mut self, rtol: f64) -> Self {
+        self.settings.rtol = rtol;
+        self
+    }
+
+    /// Set the absolute tolerance
+    pub fn atol(mut self, atol: f64) -> Self {
+        self.settings.atol = atol;
+        self
+    }
+
+    /// Set the largest step size; the step size of the fixed-step methods
+    pub fn max_step(mut self, max_step: f64) -> Self {
+        self.settings.max_step = max_step;
+        self
+    }
+
+    /// Set the smallest step size of the adaptive methods
+    pub fn min_step(mut self, min_step: f64) -> Self {
+        self.settings.min_step = min_step;
+        self
+    }
+
+    /// Integrate from `t0` to `t1`
+    ///
+    /// # Arguments
+    /// * `f` - The function to integrate (dy/dt)
+    /// * `y0` - The starting state
+    /// * `t0` - The starting time
+    /// * `t1` - The final time; may be less than `t0`
+    ///
+    /// # Returns
+    /// The state at `t1` and the step statistics. Fixed-step methods
+    /// return `SCError::InvalidInput` if `max_step` is not finite and
+    /// positive; see `rk45_integrate` for the adaptive errors
+    ///
+    pub fn integrate<F, S>(&self, f: F, y0: S, t0: f64, t1: f64) -> SCResult<(S, IntegrationStats)>
+    where
+        F: Fn(f64, &S) -> S,
+        S: ODEState + std::ops::Div<f64, Output = S>,
+    {
+        let evals = match self.method {
+            IntegrationMethod::RK45 => return rk45_integrate(f, t0, y0, t1, &self.settings),
+            IntegrationMethod::RK4 => 4,
+            IntegrationMethod::RK8 => 11,
+        };
+        let h = self.settings.max_step;
+        if !(h.is_finite() && h > 0.0) {
+            return Err(SCError::InvalidInput);
+        }
+
+        let dir = (t1 - t0).signum();
+        let mut stats = IntegrationStats::default();
+        let mut t = t0;
+        let mut y = y0;
+        while (t1 - t) * dir > 0.0 {
+            let last = (t1 - t).abs() <= h;
+            let hs = if last { t1 - t } else { dir * h };
+            y = match self.method {
+                IntegrationMethod::RK8 => rk8_integrate(&f, t, y, hs),
+                _ => rk4_integrate(&f, t, y, hs),
+            };
+            y.ode_post_step();
+            t = if last { t1 } else { t + hs };
+            stats.accepted_steps += 1;
+            stats.function_evals += evals;
+            stats.max_step = stats.max_step.max(hs.abs());
+            stats.min_step = if stats.accepted_steps == 1 {
+                hs.abs()
+            } else {
+                stats.min_step.min(hs.abs())
+            };
+        }
+        Ok((y, stats))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vector2;
+
+    #[test]
+    fn test_integrator_builder() {
+        let f = |_x: f64, y: &Vector2| Vector2::from_vec([y[1], -y[0]]);
+        let y0 = Vector2::from_vec([1.0, 0.0]);
+        let t1 = 2.05;
+
+        for (method, tol) in [
+            (IntegrationMethod::RK4, 1.0e-5),
+            (IntegrationMethod::RK8, 1.0e-12),
+            (IntegrationMethod::RK45, 1.0e-9),
+        ] {
+            let integrator = Integrator::new()
+                .method(method)
+                .rtol(1.0e-11)
+                .atol(1.0e-11)
+                .max_step(0.1);
+            let (y, stats) = integrator.integrate(f, y0, 0.0, t1).unwrap();
+            assert!((y[0] - t1.cos()).abs() < tol);
+            assert!((y[1] + t1.sin()).abs() < tol);
+            assert!(stats.max_step <= 0.1);
+            if method != IntegrationMethod::RK45 {
+                // 20 full steps and a short one to land on t1
+                assert_eq!(stats.accepted_steps, 21);
+                assert!((stats.min_step - 0.05).abs() < 1.0e-12);
+            }
+
+            // And back again
+            let (y, _) = integrator.integrate(f, y, t1, 0.0).unwrap();
+            assert!((y - y0).norm() < 2.0 * tol);
+        }
+
+        // Fixed-step methods need a step size
+        assert!(matches!(
+            Integrator::new()
+                .method(IntegrationMethod::RK4)
+                .integrate(f, y0, 0.0, 1.0),
+            Err(SCError::InvalidInput)
+        ));
+    }
+}
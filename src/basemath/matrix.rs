@@ -1,17 +1,20 @@
-/// A simple fixed-size matrix library
-///
-/// Author: Steven Michael (ssmichael@gmail.com)
-/// Date: 2024-11-16
-///
-/// Description:
-///
-/// This module provides a simple fixed-size matrix library with no
-/// external dependencies. The library is designed to be used in
-/// embedded systems where dynamic memory allocation is not desired.
+//! A simple fixed-size matrix library
+//!
+//! Author: Steven Michael (ssmichael@gmail.com)
+//! Date: 2024-11-16
+//!
+//! Description:
+//!
+//! This module provides a simple fixed-size matrix library with no
+//! external dependencies. The library is designed to be used in
+//! embedded systems where dynamic memory allocation is not desired.
+
+use crate::{SCError, SCResult};
 
 /// Fixed-size matrix type
 ///
-/// Note: data storage is natively column major
+/// Note: data storage is natively column major: element (i, j) is at
+/// offset `j * M + i` of `as_flat_slice`
 ///
 #[derive(Clone, Copy)]
 pub struct Matrix<const M: usize, const N: usize> {
@@ -108,6 +111,171 @@ impl<const M: usize, const N: usize> Matrix<M, N> {
         m
     }
 
+    /// Create a new matrix from a dynamically-sized vector in column major order
+    ///
+    /// Unlike the array constructors, the length is only known at runtime,
+    /// so it is validated against the matrix size
+    ///
+    /// # Arguments
+    /// * `data` - A vector of M * N f64 values in column-major order
+    ///
+    /// # Returns
+    /// A new matrix, or `SCError::InvalidInput` if the length does not match
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Matrix;
+    /// let m = Matrix::<3, 2>::from_col_major_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+    /// assert_eq!(m[(0, 1)], 4.0);
+    /// ```
+    ///
+    pub fn from_col_major_vec(data: Vec<f64>) -> SCResult<Self> {
+        if data.len() != M * N {
+            return Err(SCError::InvalidInput);
+        }
+        Ok(Self::from_col_major_slice(&data))
+    }
+
+    /// Copy the elements into a vector in row major order
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Matrix;
+    /// let m = Matrix::<2, 2>::from_row_major_array([[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(m.to_row_major_vec(), vec![1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    ///
+    /// # Returns
+    /// A vector of M * N values, the inverse of `from_row_major_slice`
+    ///
+    pub fn to_row_major_vec(&self) -> Vec<f64> {
+        (0..M)
+            .flat_map(|i| self.data.iter().map(move |col| col[i]))
+            .collect()
+    }
+
+    /// Copy the elements into a vector in column major order
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Matrix;
+    /// let m = Matrix::<2, 2>::from_row_major_array([[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(m.to_col_major_vec(), vec![1.0, 3.0, 2.0, 4.0]);
+    /// ```
+    ///
+    /// # Returns
+    /// A vector of M * N values, the inverse of `from_col_major_slice`
+    ///
+    pub fn to_col_major_vec(&self) -> Vec<f64> {
+        self.data.iter().flatten().copied().collect()
+    }
+
+    /// Copy the elements into a vector of rows
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Matrix;
+    /// let m = Matrix::<2, 2>::from_row_major_array([[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(m.to_nested_vec(), vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// ```
+    ///
+    /// # Returns
+    /// M vectors, each holding the N values of one row
+    ///
+    pub fn to_nested_vec(&self) -> Vec<Vec<f64>> {
+        (0..M)
+            .map(|i| self.data.iter().map(|col| col[i]).collect())
+            .collect()
+    }
+
+    /// Format the matrix as comma-separated values
+    ///
+    /// Each row is written on its own line, so a `Vector` is written with
+    /// one value per line. Values use the shortest representation that
+    /// parses back to the same `f64`
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::{Matrix, Vector2};
+    /// let m = Matrix::<2, 2>::from_row_major_array([[1.0, 2.5], [-3.0, 4.0]]);
+    /// assert_eq!(m.to_csv(), "1,2.5\n-3,4\n");
+    /// assert_eq!(Vector2::from_vec([1.0, 2.0]).to_csv(), "1\n2\n");
+    /// ```
+    ///
+    /// # Returns
+    /// M lines of N comma-separated values, each ending in a newline
+    ///
+    pub fn to_csv(&self) -> String {
+        let mut s = String::new();
+        for i in 0..M {
+            for j in 0..N {
+                if j > 0 {
+                    s.push(',');
+                }
+                s.push_str(&self.data[j][i].to_string());
+            }
+            s.push('\n');
+        }
+        s
+    }
+
+    /// Parse a matrix from comma-separated values
+    ///
+    /// The inverse of `to_csv`: one row per line, with values separated by
+    /// commas. Whitespace around values, `\r\n` line endings, and trailing
+    /// blank lines are accepted
+    ///
+    /// # Arguments
+    /// * `s` - The text to parse
+    ///
+    /// # Returns
+    /// The matrix, or `SCError::InvalidInput` if there are not exactly M
+    /// rows of N values or a value does not parse
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Matrix;
+    /// let m = Matrix::<2, 3>::from_csv("1, 2, 3\n4, 5, 6\n").unwrap();
+    /// assert_eq!(m[(1, 0)], 4.0);
+    /// assert!(Matrix::<3, 2>::from_csv("1, 2, 3\n4, 5, 6\n").is_err());
+    /// ```
+    ///
+    pub fn from_csv(s: &str) -> SCResult<Self> {
+        let lines: Vec<&str> = s.trim_end().lines().collect();
+        if lines.len() != M {
+            return Err(SCError::InvalidInput);
+        }
+        let mut m = Self::zeros();
+        for (i, line) in lines.iter().enumerate() {
+            let fields: Vec<&str> = line.split(',').collect();
+            if fields.len() != N {
+                return Err(SCError::InvalidInput);
+            }
+            for (j, field) in fields.iter().enumerate() {
+                m.data[j][i] = field.trim().parse().map_err(|_| SCError::InvalidInput)?;
+            }
+        }
+        Ok(m)
+    }
+
+    /// Return the backing store as a flat slice, without copying
+    ///
+    /// Storage is column major, so element (i, j) is at index `j * M + i`
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Matrix;
+    /// let m = Matrix::<2, 2>::from_row_major_array([[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(m.as_flat_slice(), &[1.0, 3.0, 2.0, 4.0]);
+    /// ```
+    ///
+    /// # Returns
+    /// A slice of the M * N elements in column-major order
+    ///
+    pub fn as_flat_slice(&self) -> &[f64] {
+        self.data.as_flattened()
+    }
+
     /// Create a new matrix with all elements set to zero
     /// # Example
     /// ```
@@ -163,13 +331,62 @@ impl<const M: usize, const N: usize> Matrix<M, N> {
     }
 
     /// Get the element at the given row and column
+    ///
+    /// # Panics
+    /// If the row or column is out of bounds; see `try_get`
     pub fn get(&self, row: usize, col: usize) -> f64 {
-        self.data[row][col]
+        self.data[col][row]
     }
 
     /// Set the element at the given row and column
+    ///
+    /// # Panics
+    /// If the row or column is out of bounds; see `try_set`
     pub fn set(&mut self, row: usize, col: usize, value: f64) {
-        self.data[row][col] = value;
+        self.data[col][row] = value;
+    }
+
+    /// Get the element at the given row and column, checking bounds
+    ///
+    /// # Arguments
+    /// * `row` - Row index
+    /// * `col` - Column index
+    ///
+    /// # Returns
+    /// The element, or `SCError::InvalidMatrixIndex` if out of bounds
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Matrix;
+    /// let m = Matrix::<3, 2>::from_row_major_array([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    /// assert_eq!(m.try_get(2, 1).unwrap(), 6.0);
+    /// assert!(m.try_get(1, 2).is_err());
+    /// ```
+    ///
+    pub fn try_get(&self, row: usize, col: usize) -> SCResult<f64> {
+        if row >= M || col >= N {
+            return Err(SCError::InvalidMatrixIndex);
+        }
+        Ok(self.data[col][row])
+    }
+
+    /// Set the element at the given row and column, checking bounds
+    ///
+    /// # Arguments
+    /// * `row` - Row index
+    /// * `col` - Column index
+    /// * `value` - The new value
+    ///
+    /// # Returns
+    /// `SCError::InvalidMatrixIndex` if out of bounds, in which case the
+    /// matrix is unchanged
+    ///
+    pub fn try_set(&mut self, row: usize, col: usize, value: f64) -> SCResult<()> {
+        if row >= M || col >= N {
+            return Err(SCError::InvalidMatrixIndex);
+        }
+        self.data[col][row] = value;
+        Ok(())
     }
 
     /// Transpose the matrix
@@ -238,6 +455,270 @@ impl<const M: usize, const N: usize> Matrix<M, N> {
         }
         Vector::<N> { data: [data] }
     }
+
+    /// Return a copy of the matrix with one row removed
+    ///
+    /// Stable Rust cannot express the `M - 1` output size, so it is a
+    /// separate const parameter `P`, checked at compile time to equal `M - 1`
+    ///
+    /// # Arguments
+    /// * `row` - The index of the row to remove; panics if out of range
+    ///
+    /// # Returns
+    /// The remaining rows, in their original order
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Matrix;
+    /// let h = Matrix::<3, 2>::from_row_major_array([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    /// let h2: Matrix<2, 2> = h.remove_row(1);
+    /// assert_eq!(h2.get(1, 0), 5.0);
+    /// ```
+    ///
+    pub fn remove_row<const P: usize>(&self, row: usize) -> Matrix<P, N> {
+        const { assert!(P + 1 == M, "P must equal M - 1") };
+        assert!(row < M, "row index out of range");
+        let mut m = Matrix::<P, N>::zeros();
+        for (dst, src) in m.data.iter_mut().zip(self.data.iter()) {
+            dst[..row].copy_from_slice(&src[..row]);
+            dst[row..].copy_from_slice(&src[row + 1..]);
+        }
+        m
+    }
+
+    /// Return a copy of the matrix with one column removed
+    ///
+    /// The output column count `Q` is checked at compile time to equal `N - 1`
+    ///
+    /// # Arguments
+    /// * `col` - The index of the column to remove; panics if out of range
+    ///
+    /// # Returns
+    /// The remaining columns, in their original order
+    ///
+    pub fn remove_column<const Q: usize>(&self, col: usize) -> Matrix<M, Q> {
+        const { assert!(Q + 1 == N, "Q must equal N - 1") };
+        assert!(col < N, "column index out of range");
+        let mut m = Matrix::<M, Q>::zeros();
+        m.data[..col].copy_from_slice(&self.data[..col]);
+        m.data[col..].copy_from_slice(&self.data[col + 1..]);
+        m
+    }
+
+    /// Return a copy of the matrix with a row inserted
+    ///
+    /// The output row count `P` is checked at compile time to equal `M + 1`
+    ///
+    /// # Arguments
+    /// * `row` - The index of the new row; `M` appends it; panics if greater
+    /// * `values` - The elements of the new row
+    ///
+    /// # Returns
+    /// The matrix with `values` at row index `row` and the following rows
+    /// shifted down
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::{Matrix, Vector2};
+    /// let h = Matrix::<2, 2>::identity();
+    /// let h3: Matrix<3, 2> = h.insert_row(2, &Vector2::from_vec([5.0, 6.0]));
+    /// assert_eq!(h3.get(2, 1), 6.0);
+    /// ```
+    ///
+    pub fn insert_row<const P: usize>(&self, row: usize, values: &Vector<N>) -> Matrix<P, N> {
+        const { assert!(P == M + 1, "P must equal M + 1") };
+        assert!(row <= M, "row index out of range");
+        let mut m = Matrix::<P, N>::zeros();
+        for (j, (dst, src)) in m.data.iter_mut().zip(self.data.iter()).enumerate() {
+            dst[..row].copy_from_slice(&src[..row]);
+            dst[row] = values[j];
+            dst[row + 1..].copy_from_slice(&src[row..]);
+        }
+        m
+    }
+
+    /// Return a copy of the matrix with a column inserted
+    ///
+    /// The output column count `Q` is checked at compile time to equal `N + 1`
+    ///
+    /// # Arguments
+    /// * `col` - The index of the new column; `N` appends it; panics if greater
+    /// * `values` - The elements of the new column
+    ///
+    /// # Returns
+    /// The matrix with `values` at column index `col` and the following
+    /// columns shifted right
+    ///
+    pub fn insert_column<const Q: usize>(&self, col: usize, values: &Vector<M>) -> Matrix<M, Q> {
+        const { assert!(Q == N + 1, "Q must equal N + 1") };
+        assert!(col <= N, "column index out of range");
+        let mut m = Matrix::<M, Q>::zeros();
+        m.data[..col].copy_from_slice(&self.data[..col]);
+        m.data[col] = values.data[0];
+        m.data[col + 1..].copy_from_slice(&self.data[col..]);
+        m
+    }
+
+    /// Return the rank of the matrix
+    ///
+    /// The rank is computed via Gaussian elimination with partial pivoting,
+    /// counting pivots whose magnitude exceeds the tolerance
+    ///
+    /// # Arguments
+    /// * `tol` - Pivots with magnitude at or below this value are treated as zero
+    ///
+    /// # Returns
+    /// The number of linearly independent rows (or columns)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Matrix;
+    /// let m = Matrix::<3, 3>::identity();
+    /// assert_eq!(m.rank(1.0e-12), 3);
+    /// ```
+    ///
+    pub fn rank(&self, tol: f64) -> usize {
+        let mut data = self.data;
+        let mut rank = 0;
+        for col in 0..N {
+            if rank >= M {
+                break;
+            }
+            // Find the largest-magnitude pivot in this column
+            let mut pivot = rank;
+            for row in rank + 1..M {
+                if data[col][row].abs() > data[col][pivot].abs() {
+                    pivot = row;
+                }
+            }
+            if data[col][pivot].abs() <= tol {
+                continue;
+            }
+            for c in data.iter_mut() {
+                c.swap(rank, pivot);
+            }
+            for row in rank + 1..M {
+                let factor = data[col][row] / data[col][rank];
+                for c in data.iter_mut().skip(col) {
+                    c[row] -= factor * c[rank];
+                }
+            }
+            rank += 1;
+        }
+        rank
+    }
+
+    /// Frobenius inner product with another matrix of the same size
+    ///
+    /// # Arguments
+    /// * `other` - The other matrix
+    ///
+    /// # Returns
+    /// The sum of element-wise products, equal to trace(Aᵀ·B)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Matrix;
+    /// let a = Matrix::<2, 2>::from_row_major_array([[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(a.frobenius_inner(&a), 30.0);
+    /// ```
+    ///
+    pub fn frobenius_inner(&self, other: &Matrix<M, N>) -> f64 {
+        self.data
+            .iter()
+            .flatten()
+            .zip(other.data.iter().flatten())
+            .map(|(a, b)| a * b)
+            .sum()
+    }
+
+    /// Check whether any element satisfies a predicate
+    ///
+    /// # Arguments
+    /// * `pred` - Predicate applied to each element
+    ///
+    /// # Returns
+    /// True if `pred` is true for at least one element
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Matrix;
+    /// let m = Matrix::<2, 2>::from_row_major_array([[1.0, -2.0], [3.0, 4.0]]);
+    /// assert!(m.any(|x| x < 0.0));
+    /// ```
+    ///
+    pub fn any(&self, pred: impl Fn(f64) -> bool) -> bool {
+        self.data.iter().flatten().any(|x| pred(*x))
+    }
+
+    /// Check whether all elements satisfy a predicate
+    ///
+    /// # Arguments
+    /// * `pred` - Predicate applied to each element
+    ///
+    /// # Returns
+    /// True if `pred` is true for every element
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Matrix;
+    /// let m = Matrix::<2, 2>::from_row_major_array([[1.0, 2.0], [3.0, 4.0]]);
+    /// assert!(m.all(|x| x > 0.0));
+    /// ```
+    ///
+    pub fn all(&self, pred: impl Fn(f64) -> bool) -> bool {
+        self.data.iter().flatten().all(|x| pred(*x))
+    }
+
+    /// Count the elements greater than a threshold
+    ///
+    /// # Arguments
+    /// * `threshold` - Value to compare against
+    ///
+    /// # Returns
+    /// The number of elements strictly greater than `threshold`
+    ///
+    pub fn count_greater(&self, threshold: f64) -> usize {
+        self.data
+            .iter()
+            .flatten()
+            .filter(|x| **x > threshold)
+            .count()
+    }
+
+    /// Check that every element is finite
+    ///
+    /// # Returns
+    /// False if any element is NaN or infinite
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Vector3;
+    /// assert!(Vector3::xhat().is_finite());
+    /// assert!(!Vector3::from_vec([1.0, f64::NAN, 0.0]).is_finite());
+    /// ```
+    ///
+    pub fn is_finite(&self) -> bool {
+        self.all(f64::is_finite)
+    }
+
+    /// Check whether the matrix is zero to within a tolerance
+    ///
+    /// # Arguments
+    /// * `tol` - Largest allowed element magnitude
+    ///
+    /// # Returns
+    /// True if every element has magnitude at most `tol`; false if any
+    /// element is NaN
+    ///
+    pub fn is_zero(&self, tol: f64) -> bool {
+        self.all(|x| x.abs() <= tol)
+    }
 }
 
 /// Implementations for equality comparison
@@ -362,12 +843,49 @@ impl<const M: usize, const N: usize> std::ops::Div<f64> for Matrix<M, N> {
     }
 }
 
-/// Add a scalar to a matrix
-impl<const M: usize, const N: usize> std::ops::Add<f64> for Matrix<M, N> {
-    type Output = Self;
+/// Scale by an `i32`, converted losslessly to f64
+///
+/// Only one integer and one float scalar type are supported, so that
+/// unsuffixed literals such as `2 * m` and `2.0 * m` remain unambiguous
+macro_rules! impl_scalar_ops {
+    ($($t:ty),*) => {$(
+        /// Multiply matrix by a scalar
+        impl<const M: usize, const N: usize> std::ops::Mul<$t> for Matrix<M, N> {
+            type Output = Self;
+
+            fn mul(self, rhs: $t) -> Self::Output {
+                self * f64::from(rhs)
+            }
+        }
 
-    fn add(self, rhs: f64) -> Self::Output {
-        Matrix {
+        /// Left-multiply scalar by a matrix
+        impl<const M: usize, const N: usize> std::ops::Mul<Matrix<M, N>> for $t {
+            type Output = Matrix<M, N>;
+
+            fn mul(self, rhs: Matrix<M, N>) -> Self::Output {
+                rhs * f64::from(self)
+            }
+        }
+
+        /// Divide matrix by a scalar
+        impl<const M: usize, const N: usize> std::ops::Div<$t> for Matrix<M, N> {
+            type Output = Self;
+
+            fn div(self, rhs: $t) -> Self::Output {
+                self / f64::from(rhs)
+            }
+        }
+    )*};
+}
+
+impl_scalar_ops!(i32);
+
+/// Add a scalar to a matrix
+impl<const M: usize, const N: usize> std::ops::Add<f64> for Matrix<M, N> {
+    type Output = Self;
+
+    fn add(self, rhs: f64) -> Self::Output {
+        Matrix {
             data: {
                 let mut data = [[0.0; M]; N];
                 for (i, row) in data.iter_mut().enumerate() {
@@ -401,6 +919,44 @@ impl<const M: usize, const N: usize> std::ops::SubAssign<Self> for Matrix<M, N>
     }
 }
 
+/// Add reference matrix in place
+///
+/// # Example
+/// ```
+/// use satctrl::Matrix;
+/// let mut m = Matrix::<3, 3>::identity();
+/// let other = Matrix::<3, 3>::ones();
+/// m += &other;
+/// ```
+impl<const M: usize, const N: usize> std::ops::AddAssign<&Matrix<M, N>> for Matrix<M, N> {
+    fn add_assign(&mut self, rhs: &Matrix<M, N>) {
+        for i in 0..N {
+            for j in 0..M {
+                self.data[i][j] += rhs.data[i][j];
+            }
+        }
+    }
+}
+
+/// Subtract reference matrix in place
+///
+/// # Example
+/// ```
+/// use satctrl::Matrix;
+/// let mut m = Matrix::<3, 3>::identity();
+/// let other = Matrix::<3, 3>::ones();
+/// m -= &other;
+/// ```
+impl<const M: usize, const N: usize> std::ops::SubAssign<&Matrix<M, N>> for Matrix<M, N> {
+    fn sub_assign(&mut self, rhs: &Matrix<M, N>) {
+        for i in 0..N {
+            for j in 0..M {
+                self.data[i][j] -= rhs.data[i][j];
+            }
+        }
+    }
+}
+
 /// Add two matrices
 impl<const M: usize, const N: usize> std::ops::Add<Matrix<M, N>> for Matrix<M, N> {
     type Output = Self;
@@ -640,6 +1196,38 @@ impl<const M: usize, const N: usize> std::ops::IndexMut<(usize, usize)> for Matr
     }
 }
 
+/// View a vector as a slice of its elements
+impl<const N: usize> AsRef<[f64]> for Vector<N> {
+    fn as_ref(&self) -> &[f64] {
+        self.as_slice()
+    }
+}
+
+/// View a vector as a mutable slice of its elements
+impl<const N: usize> AsMut<[f64]> for Vector<N> {
+    fn as_mut(&mut self) -> &mut [f64] {
+        self.as_mut_slice()
+    }
+}
+
+/// Convert a vector into an array of its elements
+///
+/// The conversion cannot fail; `TryInto` is also available through the
+/// standard blanket implementation
+///
+/// # Example
+/// ```
+/// use satctrl::Vector3;
+/// let a: [f64; 3] = Vector3::from_vec([1.0, 2.0, 3.0]).into();
+/// assert_eq!(a, [1.0, 2.0, 3.0]);
+/// ```
+///
+impl<const N: usize> From<Vector<N>> for [f64; N] {
+    fn from(v: Vector<N>) -> Self {
+        v.data[0]
+    }
+}
+
 /// Implementations for square matrices
 impl<const M: usize> Matrix<M, M> {
     /// Create a new diagonal square matrix given input diagonal elements (trace)
@@ -700,7 +1288,61 @@ impl<const M: usize> Matrix<M, M> {
         sum
     }
 
+    /// Check whether the matrix is orthogonal
+    ///
+    /// # Arguments
+    /// * `tol` - Largest allowed element-wise deviation of Aᵀ·A from identity
+    ///
+    /// # Returns
+    /// True if every element of Aᵀ·A - I has magnitude at most `tol`
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Quaternion;
+    /// let m = Quaternion::rotz(0.3).as_dcm();
+    /// assert!(m.is_orthogonal(1.0e-12));
+    /// ```
+    ///
+    pub fn is_orthogonal(&self, tol: f64) -> bool {
+        let residual = self.transpose() * *self - Self::identity();
+        residual.data.iter().flatten().all(|v| v.abs() <= tol)
+    }
+
+    /// Return the symmetric part of the matrix, (A + Aᵀ) / 2
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Matrix;
+    /// let m = Matrix::<2, 2>::from_row_major_array([[1.0, 2.0], [4.0, 3.0]]);
+    /// let s = m.symmetric_part();
+    /// assert_eq!(s[(0, 1)], 3.0);
+    /// assert_eq!(s, s.transpose());
+    /// ```
+    ///
+    pub fn symmetric_part(&self) -> Self {
+        (*self + self.transpose()) * 0.5
+    }
+
+    /// Return the antisymmetric (skew-symmetric) part of the matrix, (A - Aᵀ) / 2
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Matrix;
+    /// let m = Matrix::<2, 2>::from_row_major_array([[1.0, 2.0], [4.0, 3.0]]);
+    /// let w = m.antisymmetric_part();
+    /// assert_eq!(w[(0, 1)], -1.0);
+    /// assert_eq!(w, w.transpose() * -1.0);
+    /// ```
+    ///
+    pub fn antisymmetric_part(&self) -> Self {
+        (*self - self.transpose()) * 0.5
+    }
+
     /// Return the determinant of the matrix
+    ///
+    /// Computed from an LU decomposition with partial pivoting, so
+    /// numerically singular matrices give a determinant near zero
+    ///
     /// # Example
     /// ```
     /// use satctrl::Matrix;
@@ -711,30 +1353,91 @@ impl<const M: usize> Matrix<M, M> {
     /// The determinant of the matrix
     ///
     pub fn determinant(&self) -> f64 {
-        let mut data = self.data;
+        // Product of the pivots from the partially-pivoted LU decomposition
+        let (_, u, perm) = match self.lu() {
+            Some(lu) => lu,
+            None => return 0.0,
+        };
         let mut det = 1.0;
         for i in 0..M {
-            if data[i][i] == 0.0 {
-                for j in i + 1..M {
-                    if data[j][i] != 0.0 {
-                        data.swap(i, j);
-                        det = -det;
-                        break;
-                    }
+            det *= u[(i, i)];
+        }
+        // Each cycle of length n in the permutation is n - 1 row swaps
+        let mut visited = [false; M];
+        for start in 0..M {
+            let mut i = start;
+            let mut len = 0;
+            while !visited[i] {
+                visited[i] = true;
+                i = perm[i];
+                len += 1;
+            }
+            if len > 0 && len % 2 == 0 {
+                det = -det;
+            }
+        }
+        det
+    }
+
+    /// LU decomposition with partial pivoting
+    ///
+    /// Factor the matrix such that `P * A = L * U`, where `P` is a
+    /// permutation matrix, `L` is unit lower triangular, and `U` is
+    /// upper triangular
+    ///
+    /// # Returns
+    /// A tuple `(L, U, perm)` if the matrix is non-singular, None otherwise.
+    /// Row `i` of `P * A` is row `perm[i]` of `A`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Matrix;
+    /// let m = Matrix::<2, 2>::from_row_major_array([[1.0, 2.0], [3.0, 4.0]]);
+    /// let (l, u, perm) = m.lu().unwrap();
+    /// assert_eq!(perm, [1, 0]);
+    /// ```
+    ///
+    #[allow(clippy::type_complexity)]
+    pub fn lu(&self) -> Option<(Self, Self, [usize; M])> {
+        let mut l = Self::identity();
+        let mut u = *self;
+        let mut perm = [0; M];
+        for (i, p) in perm.iter_mut().enumerate() {
+            *p = i;
+        }
+
+        for k in 0..M {
+            let mut max = k;
+            for i in k + 1..M {
+                if u[(i, k)].abs() > u[(max, k)].abs() {
+                    max = i;
                 }
             }
-            if data[i][i] == 0.0 {
-                return 0.0;
+            if u[(max, k)] == 0.0 {
+                return None;
             }
-            det *= data[i][i];
-            for j in i + 1..M {
-                let factor = data[j][i] / data[i][i];
-                for k in i + 1..M {
-                    data[j][k] -= factor * data[i][k];
+            if max != k {
+                // data is column major, so swap the row within each column
+                for col in u.data.iter_mut() {
+                    col.swap(k, max);
+                }
+                for col in l.data.iter_mut().take(k) {
+                    col.swap(k, max);
+                }
+                perm.swap(k, max);
+            }
+
+            for i in k + 1..M {
+                let factor = u[(i, k)] / u[(k, k)];
+                l[(i, k)] = factor;
+                u[(i, k)] = 0.0;
+                for j in k + 1..M {
+                    u[(i, j)] -= factor * u[(k, j)];
                 }
             }
         }
-        det
+        Some((l, u, perm))
     }
 
     /// Return the inverse of the matrix if matrix is non-singular
@@ -751,52 +1454,105 @@ impl<const M: usize> Matrix<M, M> {
     /// ```
     ///
     pub fn inverse(&self) -> Option<Self> {
-        let n = M;
-        let mut lu = *self;
-        let mut p = (0..n).collect::<Vec<_>>();
-
-        // LU decomposition with partial pivoting
-        for i in 0..n {
-            let mut max = i;
-            for j in i + 1..n {
-                if lu.data[j][i].abs() > lu.data[max][i].abs() {
-                    max = j;
+        let (l, u, perm) = self.lu()?;
+
+        // Solve L * U * x = P * e_j for each column j of the identity
+        let mut inv = Self::zeros();
+        for j in 0..M {
+            let mut x = [0.0; M];
+            for i in 0..M {
+                x[i] = if perm[i] == j { 1.0 } else { 0.0 };
+                for k in 0..i {
+                    x[i] -= l[(i, k)] * x[k];
                 }
             }
-            if lu.data[max][i] == 0.0 {
-                return None;
-            }
-            lu.data.swap(i, max);
-            p.swap(i, max);
-
-            for j in i + 1..n {
-                lu.data[j][i] /= lu.data[i][i];
-                for k in i + 1..n {
-                    lu.data[j][k] -= lu.data[j][i] * lu.data[i][k];
+            for i in (0..M).rev() {
+                for k in i + 1..M {
+                    x[i] -= u[(i, k)] * x[k];
                 }
+                x[i] /= u[(i, i)];
             }
+            inv.data[j] = x;
         }
+        Some(inv)
+    }
 
-        // Inverse calculation
-        let mut inv = Self::identity();
-        for (i, &pi) in p.iter().enumerate() {
-            for j in 0..n {
-                inv.data[i][j] = if pi == j { 1.0 } else { 0.0 };
-                for k in 0..i {
-                    inv.data[i][j] -= lu.data[i][k] * inv.data[k][j];
-                }
+    /// Symmetric square root of a symmetric positive-definite matrix
+    ///
+    /// Computed from the eigen-decomposition as `V·diag(√λ)·Vᵀ`. Unlike
+    /// the Cholesky factor, the result is itself symmetric
+    ///
+    /// # Returns
+    /// The matrix `S` with `S·S = self`, or
+    /// `SCError::NonPositiveDefiniteMatrix` if any eigenvalue is not
+    /// positive
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Matrix;
+    /// let p = Matrix::<2, 2>::from_row_major_array([[4.0, 0.0], [0.0, 9.0]]);
+    /// let s = p.sqrtm().unwrap();
+    /// assert!((s[(1, 1)] - 3.0).abs() < 1.0e-12);
+    /// ```
+    ///
+    pub fn sqrtm(&self) -> SCResult<Self> {
+        let (d, v) = crate::matrixutils::symmetric_eigen(self)?;
+        let mut vs = v;
+        for j in 0..M {
+            if d[j] <= 0.0 {
+                return Err(SCError::NonPositiveDefiniteMatrix);
             }
-        }
-        for i in (0..n).rev() {
-            for j in 0..n {
-                for k in i + 1..n {
-                    inv.data[i][j] -= lu.data[i][k] * inv.data[k][j];
-                }
-                inv.data[i][j] /= lu.data[i][i];
+            let s = d[j].sqrt();
+            for x in vs.data[j].iter_mut() {
+                *x *= s;
             }
         }
+        Ok(vs * v.transpose())
+    }
 
-        Some(inv)
+    /// Dominant eigenvalue and eigenvector by power iteration
+    ///
+    /// The eigenvalue estimate at each iteration is the Rayleigh quotient
+    /// of the current unit vector, which converges twice as fast as the
+    /// vector itself for symmetric matrices. Convergence requires a single
+    /// real eigenvalue of largest magnitude; e.g. a complex pair or the
+    /// pair ±λ does not converge
+    ///
+    /// # Arguments
+    /// * `iters` - The largest number of iterations
+    /// * `tol` - Convergence tolerance on the residual `|A x - λ x|`,
+    ///   relative to `|λ|`
+    ///
+    /// # Returns
+    /// The eigenvalue of largest magnitude and its unit eigenvector, or
+    /// `SCError::NotConverged` if the tolerance is not met within `iters`
+    /// iterations. The zero matrix returns an eigenvalue of 0
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Matrix;
+    /// let a = Matrix::<2, 2>::from_row_major_array([[2.0, 1.0], [1.0, 2.0]]);
+    /// let (lambda, _) = a.dominant_eigenvalue(100, 1.0e-12).unwrap();
+    /// assert!((lambda - 3.0).abs() < 1.0e-12);
+    /// ```
+    ///
+    pub fn dominant_eigenvalue(&self, iters: usize, tol: f64) -> SCResult<(f64, Vector<M>)> {
+        // Start away from any special direction
+        let mut x = Vector::<M>::zeros();
+        for i in 0..M {
+            x[i] = 1.0 + 0.1 * i as f64;
+        }
+        x = x / x.norm();
+        for _ in 0..iters {
+            let y = *self * x;
+            let lambda = x.dot(&y);
+            let resid = (y - x * lambda).norm();
+            if resid <= tol * lambda.abs() || y.norm() == 0.0 {
+                return Ok((lambda, x));
+            }
+            x = y / y.norm();
+        }
+        Err(SCError::NotConverged)
     }
 }
 
@@ -835,6 +1591,77 @@ impl<const N: usize> Vector<N> {
         v
     }
 
+    /// Create a new vector from a dynamically-sized vector
+    ///
+    /// # Arguments
+    /// * `data` - A vector of N f64 values
+    ///
+    /// # Returns
+    /// A new vector, or `SCError::InvalidInput` if the length does not match
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Vector;
+    /// let v = Vector::<3>::try_from_vec(vec![1.0, 2.0, 3.0]).unwrap();
+    /// ```
+    ///
+    pub fn try_from_vec(data: Vec<f64>) -> SCResult<Self> {
+        if data.len() != N {
+            return Err(SCError::InvalidInput);
+        }
+        Ok(Self::from_slice(&data))
+    }
+
+    /// Concatenate two vectors
+    ///
+    /// Stable Rust cannot express the `N + P` output length, so it is a
+    /// separate const parameter `Q`, checked at compile time to equal `N + P`
+    ///
+    /// # Arguments
+    /// * `other` - The vector to append
+    ///
+    /// # Returns
+    /// A vector with the elements of `self` followed by those of `other`
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::{Vector3, Vector6};
+    /// let pos = Vector3::from_vec([1.0, 2.0, 3.0]);
+    /// let vel = Vector3::from_vec([4.0, 5.0, 6.0]);
+    /// let state: Vector6 = pos.concat(&vel);
+    /// ```
+    ///
+    pub fn concat<const P: usize, const Q: usize>(&self, other: &Vector<P>) -> Vector<Q> {
+        const { assert!(Q == N + P, "Q must equal N + P") };
+        let mut v = Vector::<Q>::zeros();
+        v.data[0][..N].copy_from_slice(&self.data[0]);
+        v.data[0][N..].copy_from_slice(&other.data[0]);
+        v
+    }
+
+    /// Split a vector into two parts
+    ///
+    /// The part lengths `A` and `B` are checked at compile time to sum to `N`
+    ///
+    /// # Returns
+    /// The first `A` elements and the remaining `B` elements
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::{Vector3, Vector6};
+    /// let state = Vector6::from_vec([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// let (pos, vel): (Vector3, Vector3) = state.split();
+    /// assert_eq!(vel[0], 4.0);
+    /// ```
+    ///
+    pub fn split<const A: usize, const B: usize>(&self) -> (Vector<A>, Vector<B>) {
+        const { assert!(A + B == N, "A + B must equal N") };
+        (
+            Vector::<A>::from_slice(&self.data[0][..A]),
+            Vector::<B>::from_slice(&self.data[0][A..]),
+        )
+    }
+
     /// return vector as a slice
     ///
     /// # Example
@@ -921,6 +1748,154 @@ impl<const N: usize> Vector<N> {
     pub fn normsq(&self) -> f64 {
         self.dot(self)
     }
+
+    /// Return the dot product of two vectors using compensated summation
+    ///
+    /// Each product is split into its rounded value and exact error with a
+    /// fused multiply-add, and the terms are accumulated with Neumaier's
+    /// variant of Kahan summation. The result is as accurate as if computed
+    /// in twice the working precision, at a few times the cost of `dot`
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Vector;
+    /// let v1 = Vector::<3>::from_vec([1.0e16, 1.0, -1.0e16]);
+    /// let v2 = Vector::<3>::from_vec([1.0, 1.0, 1.0]);
+    /// assert_eq!(v1.dot(&v2), 0.0);
+    /// assert_eq!(v1.dot_accurate(&v2), 1.0);
+    /// ```
+    ///
+    /// # Returns
+    /// The dot product of the two vectors
+    ///
+    pub fn dot_accurate(&self, other: &Self) -> f64 {
+        let mut sum = 0.0;
+        let mut comp = 0.0;
+        for i in 0..N {
+            let p = self.data[0][i] * other.data[0][i];
+            comp += self.data[0][i].mul_add(other.data[0][i], -p);
+            let t = sum + p;
+            if f64::abs(sum) >= f64::abs(p) {
+                comp += (sum - t) + p;
+            } else {
+                comp += (p - t) + sum;
+            }
+            sum = t;
+        }
+        sum + comp
+    }
+
+    /// Return the norm of the vector using compensated summation
+    ///
+    /// # Returns
+    /// The norm of the vector, computed from `dot_accurate`
+    ///
+    pub fn norm_accurate(&self) -> f64 {
+        self.dot_accurate(self).sqrt()
+    }
+
+    /// Return the L1 norm, the sum of the absolute values of the elements
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Vector3;
+    /// assert_eq!(Vector3::from_vec([3.0, -4.0, 0.0]).norm_l1(), 7.0);
+    /// ```
+    ///
+    pub fn norm_l1(&self) -> f64 {
+        self.data[0].iter().map(|x| x.abs()).sum()
+    }
+
+    /// Return the L-infinity norm, the largest absolute value of the elements
+    ///
+    /// NaN if any element is NaN
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Vector3;
+    /// assert_eq!(Vector3::from_vec([3.0, -4.0, 0.0]).norm_inf(), 4.0);
+    /// ```
+    ///
+    pub fn norm_inf(&self) -> f64 {
+        // `f64::max` drops NaN, so propagate it explicitly
+        self.data[0].iter().fold(0.0, |m: f64, x| {
+            if x.is_nan() || m.is_nan() {
+                f64::NAN
+            } else {
+                m.max(x.abs())
+            }
+        })
+    }
+
+    /// Return the p-norm, (Σ |xᵢ|^p)^(1/p)
+    ///
+    /// The elements are scaled by the largest magnitude before raising to
+    /// the power `p`, so large `p` does not overflow
+    ///
+    /// # Arguments
+    /// * `p` - The order of the norm; at least 1 for a true norm.
+    ///   `f64::INFINITY` gives the L-infinity norm
+    ///
+    /// # Returns
+    /// The p-norm of the vector
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Vector3;
+    /// let v = Vector3::from_vec([3.0, -4.0, 0.0]);
+    /// assert!((v.norm_p(2.0) - 5.0).abs() < 1.0e-15);
+    /// ```
+    ///
+    pub fn norm_p(&self, p: f64) -> f64 {
+        let m = self.norm_inf();
+        if p == f64::INFINITY || m == 0.0 || !m.is_finite() {
+            return m;
+        }
+        let sum: f64 = self.data[0].iter().map(|x| (x.abs() / m).powf(p)).sum();
+        m * sum.powf(1.0 / p)
+    }
+
+    /// Return the quadratic form xᵀ·A·x, with x the vector
+    ///
+    /// # Arguments
+    /// * `a` - The N x N matrix of the form
+    ///
+    /// # Returns
+    /// The scalar xᵀ·A·x. Its gradient with respect to x is (A + Aᵀ)·x
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::{Matrix2, Vector2};
+    /// let a = Matrix2::from_row_major_array([[2.0, 1.0], [0.0, 3.0]]);
+    /// let x = Vector2::from_vec([1.0, 2.0]);
+    /// assert_eq!(x.quadratic_form(&a), 16.0);
+    /// ```
+    ///
+    pub fn quadratic_form(&self, a: &Matrix<N, N>) -> f64 {
+        self.bilinear(a, self)
+    }
+
+    /// Return the bilinear form xᵀ·A·y, with x the vector
+    ///
+    /// # Arguments
+    /// * `a` - The N x P matrix of the form
+    /// * `other` - The vector y, of length P
+    ///
+    /// # Returns
+    /// The scalar xᵀ·A·y
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::{Matrix, Vector};
+    /// let a = Matrix::<2, 3>::from_row_major_array([[1.0, 0.0, 2.0], [0.0, 1.0, -1.0]]);
+    /// let x = Vector::<2>::from_vec([1.0, 2.0]);
+    /// let y = Vector::<3>::from_vec([3.0, 1.0, 1.0]);
+    /// assert_eq!(x.bilinear(&a, &y), 5.0);
+    /// ```
+    ///
+    pub fn bilinear<const P: usize>(&self, a: &Matrix<N, P>, other: &Vector<P>) -> f64 {
+        self.dot(&(*a * *other))
+    }
 }
 
 impl Vector<3> {
@@ -991,6 +1966,115 @@ impl Vector<3> {
         Vector::<3>::from_vec([0.0, 0.0, 1.0])
     }
 
+    /// Convert to spherical coordinates
+    ///
+    /// Uses the physics (ISO 80000-2) convention: `theta` is the
+    /// colatitude measured from the +z axis, and `phi` is the azimuth
+    /// measured from the +x axis toward the +y axis
+    ///
+    /// # Returns
+    /// A tuple (r, theta, phi) with theta in [0, π] and phi in (-π, π].
+    /// On the z axis phi is undefined and returned as 0; at the origin
+    /// theta is also returned as 0
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Vector3;
+    /// let (r, theta, phi) = Vector3::from_vec([0.0, 2.0, 0.0]).to_spherical();
+    /// assert_eq!((r, theta, phi), (2.0, std::f64::consts::FRAC_PI_2, std::f64::consts::FRAC_PI_2));
+    /// ```
+    ///
+    pub fn to_spherical(&self) -> (f64, f64, f64) {
+        let [x, y, z] = self.data[0];
+        let rho = x.hypot(y);
+        let r = rho.hypot(z);
+        if r == 0.0 {
+            return (0.0, 0.0, 0.0);
+        }
+        let theta = rho.atan2(z);
+        let phi = if rho == 0.0 { 0.0 } else { y.atan2(x) };
+        (r, theta, phi)
+    }
+
+    /// Create a vector from spherical coordinates
+    ///
+    /// See `to_spherical` for the convention
+    ///
+    /// # Arguments
+    /// * `r` - Radius
+    /// * `theta` - Colatitude from the +z axis, radians
+    /// * `phi` - Azimuth from the +x axis toward +y, radians
+    ///
+    /// # Returns
+    /// The Cartesian vector
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Vector3;
+    /// let v = Vector3::from_spherical(1.0, 0.0, 0.0);
+    /// assert_eq!(v, Vector3::zhat());
+    /// ```
+    ///
+    pub fn from_spherical(r: f64, theta: f64, phi: f64) -> Self {
+        let (st, ct) = theta.sin_cos();
+        let (sp, cp) = phi.sin_cos();
+        Vector::<3>::from_vec([r * st * cp, r * st * sp, r * ct])
+    }
+
+    /// Convert a direction to right ascension and declination
+    ///
+    /// Uses the astronomical convention: right ascension is measured in
+    /// the x-y (equatorial) plane from the +x axis (vernal equinox) toward
+    /// +y, and declination is the elevation above that plane. The vector
+    /// need not be a unit vector
+    ///
+    /// # Returns
+    /// A tuple (ra, dec) in radians, with ra in [0, 2π) and dec in
+    /// [-π/2, π/2]. At the poles ra is undefined and returned as 0
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Vector3;
+    /// let (ra, dec) = Vector3::from_vec([0.0, -1.0, 1.0]).to_radec();
+    /// assert!((ra - 1.5 * std::f64::consts::PI).abs() < 1.0e-15);
+    /// assert!((dec - std::f64::consts::FRAC_PI_4).abs() < 1.0e-15);
+    /// ```
+    ///
+    pub fn to_radec(&self) -> (f64, f64) {
+        let [x, y, z] = self.data[0];
+        let rho = x.hypot(y);
+        let ra = if rho == 0.0 {
+            0.0
+        } else {
+            crate::wrap_2pi(y.atan2(x))
+        };
+        (ra, z.atan2(rho))
+    }
+
+    /// Create a unit vector from right ascension and declination
+    ///
+    /// See `to_radec` for the convention
+    ///
+    /// # Arguments
+    /// * `ra` - Right ascension, radians
+    /// * `dec` - Declination, radians
+    ///
+    /// # Returns
+    /// The unit direction vector
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::Vector3;
+    /// let v = Vector3::from_radec(0.0, 0.0);
+    /// assert_eq!(v, Vector3::xhat());
+    /// ```
+    ///
+    pub fn from_radec(ra: f64, dec: f64) -> Self {
+        let (sd, cd) = dec.sin_cos();
+        let (sa, ca) = ra.sin_cos();
+        Vector::<3>::from_vec([cd * ca, cd * sa, sd])
+    }
+
     /// Return the angle between two vectors
     ///
     /// # Returns
@@ -1011,14 +2095,104 @@ impl Vector<3> {
         let norm = self.norm() * other.norm();
         (dot / norm).acos()
     }
+
+    /// Return the skew-symmetric cross-product matrix of the vector
+    ///
+    /// # Returns
+    /// The matrix `[v]×` such that `v.skew() * u == v.cross(&u)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Vector3;
+    /// let v = Vector3::from_vec([1.0, 2.0, 3.0]);
+    /// let u = Vector3::from_vec([-1.0, 0.5, 2.0]);
+    /// assert_eq!(v.skew() * u, v.cross(&u));
+    /// ```
+    ///
+    pub fn skew(&self) -> Matrix<3, 3> {
+        let (x, y, z) = (self.data[0][0], self.data[0][1], self.data[0][2]);
+        Matrix::<3, 3>::from_row_major_array([[0.0, -z, y], [z, 0.0, -x], [-y, x, 0.0]])
+    }
+
+    /// Rotate the vector about an arbitrary axis
+    ///
+    /// Uses Rodrigues' rotation formula directly, without constructing
+    /// a quaternion
+    ///
+    /// # Arguments
+    /// * `axis` - The rotation axis; normalized internally
+    /// * `angle` - The rotation angle in radians, right-handed about `axis`
+    ///
+    /// # Returns
+    /// The rotated vector, or the vector unchanged if `axis` is zero
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Vector3;
+    /// let v = Vector3::xhat().rotate_about(&Vector3::zhat(), std::f64::consts::FRAC_PI_2);
+    /// assert_eq!(v, Vector3::yhat());
+    /// ```
+    ///
+    pub fn rotate_about(&self, axis: &Self, angle: f64) -> Self {
+        let n = axis.norm();
+        if n == 0.0 {
+            return *self;
+        }
+        let k = *axis / n;
+        let (s, c) = angle.sin_cos();
+        *self * c + k.cross(self) * s + k * (k.dot(self) * (1.0 - c))
+    }
+
+    /// Reflect the vector across a plane through the origin
+    ///
+    /// # Arguments
+    /// * `normal` - The plane normal; normalized internally
+    ///
+    /// # Returns
+    /// v - 2 (v·n̂) n̂, or the vector unchanged if `normal` is zero
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Vector3;
+    /// let v = Vector3::from_vec([1.0, 2.0, 3.0]).reflect(&Vector3::zhat());
+    /// assert_eq!(v, Vector3::from_vec([1.0, 2.0, -3.0]));
+    /// ```
+    ///
+    pub fn reflect(&self, normal: &Self) -> Self {
+        let n = normal.norm();
+        if n == 0.0 {
+            return *self;
+        }
+        let k = *normal / n;
+        *self - k * (2.0 * k.dot(self))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::Matrix;
+    use super::SCError;
     use super::Vector;
 
+    fn assert_close<const M: usize, const N: usize>(a: &Matrix<M, N>, b: &Matrix<M, N>, tol: f64) {
+        for i in 0..M {
+            for j in 0..N {
+                assert!(
+                    (a[(i, j)] - b[(i, j)]).abs() < tol,
+                    "element ({}, {}) differs: {} vs {}",
+                    i,
+                    j,
+                    a[(i, j)],
+                    b[(i, j)]
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_multiply() {
         let m1 = Matrix::<3, 2>::from_row_major_array([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
@@ -1046,6 +2220,412 @@ mod tests {
         assert_eq!(vout, Vector::<3>::from_slice(&[14.0, 32.0, 50.0]));
     }
 
+    #[test]
+    fn test_scalar_types() {
+        let m = Matrix::<2, 2>::from_row_major_array([[1.0, 2.0], [3.0, 4.0]]);
+        assert_eq!(m * 2, m * 2.0);
+        assert_eq!(3 * m, m * 3.0);
+        assert_eq!(m / 4, m * 0.25);
+        assert_eq!(2 * m, m + m);
+        let v = Vector::<3>::from_vec([1.0, -2.0, 3.0]);
+        assert_eq!(v * -1, Vector::<3>::from_vec([-1.0, 2.0, -3.0]));
+        assert_eq!(v / 2_i32, v * 0.5);
+    }
+
+    #[test]
+    fn test_to_vec() {
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let m = Matrix::<2, 3>::from_row_major_slice(&data);
+        assert_eq!(m.to_row_major_vec(), data.to_vec());
+        assert_eq!(
+            Matrix::<2, 3>::from_col_major_slice(&m.to_col_major_vec()),
+            m
+        );
+        assert_eq!(m.to_col_major_vec(), vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+        assert_eq!(
+            m.to_nested_vec(),
+            vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]
+        );
+    }
+
+    #[test]
+    fn test_flat_slice() {
+        let m = Matrix::<2, 3>::from_row_major_array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let flat = m.as_flat_slice();
+        assert_eq!(flat.len(), 6);
+        assert_eq!(flat, &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+        assert_eq!(flat, m.to_col_major_vec().as_slice());
+        for i in 0..2 {
+            for j in 0..3 {
+                assert_eq!(flat[j * 2 + i], m[(i, j)]);
+            }
+        }
+
+        let mut v = Vector::<3>::from_vec([1.0, 2.0, 3.0]);
+        let s: &[f64] = v.as_ref();
+        assert_eq!(s, &[1.0, 2.0, 3.0]);
+        v.as_mut()[1] = -2.0;
+        let a: [f64; 3] = v.into();
+        assert_eq!(a, [1.0, -2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let m = Matrix::<2, 3>::from_col_major_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        assert_eq!(
+            m,
+            Matrix::<2, 3>::from_row_major_array([[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]])
+        );
+        assert!(matches!(
+            Matrix::<2, 3>::from_col_major_vec(vec![1.0; 5]),
+            Err(SCError::InvalidInput)
+        ));
+
+        let v = Vector::<3>::try_from_vec(vec![1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(v, Vector::<3>::from_vec([1.0, 2.0, 3.0]));
+        assert!(matches!(
+            Vector::<3>::try_from_vec(vec![1.0; 4]),
+            Err(SCError::InvalidInput)
+        ));
+    }
+
+    #[test]
+    fn test_get_set() {
+        // Non-square, so transposed indexing would be out of bounds
+        let mut m = Matrix::<3, 2>::from_row_major_array([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        for row in 0..3 {
+            for col in 0..2 {
+                assert_eq!(m.get(row, col), m[(row, col)]);
+            }
+        }
+        assert_eq!(m.get(2, 1), 6.0);
+        m.set(2, 0, -5.0);
+        assert_eq!(m[(2, 0)], -5.0);
+        assert_eq!(m.get(2, 0), -5.0);
+    }
+
+    #[test]
+    fn test_try_get_set() {
+        let mut m = Matrix::<3, 2>::from_row_major_array([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        assert_eq!(m.try_get(0, 1).unwrap(), 2.0);
+        assert_eq!(m.try_get(2, 0).unwrap(), 5.0);
+        assert!(matches!(m.try_get(3, 0), Err(SCError::InvalidMatrixIndex)));
+        assert!(matches!(m.try_get(0, 2), Err(SCError::InvalidMatrixIndex)));
+
+        m.try_set(2, 1, -1.0).unwrap();
+        assert_eq!(m[(2, 1)], -1.0);
+        assert!(matches!(
+            m.try_set(1, 2, 0.0),
+            Err(SCError::InvalidMatrixIndex)
+        ));
+
+        let v = Vector::<3>::from_vec([1.0, 2.0, 3.0]);
+        assert_eq!(v.try_get(2, 0).unwrap(), 3.0);
+        assert!(v.try_get(0, 2).is_err());
+    }
+
+    #[test]
+    fn test_determinant() {
+        let m = Matrix::<3, 3>::from_row_major_array([
+            [0.0, 2.0, 1.0],
+            [1.0, 0.0, 0.0],
+            [3.0, 1.0, 4.0],
+        ]);
+        assert!((m.determinant() + 7.0).abs() < 1.0e-12);
+
+        // Permutation matrices have determinant ±1
+        let p = Matrix::<3, 3>::from_row_major_array([
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0],
+            [1.0, 0.0, 0.0],
+        ]);
+        assert_eq!(p.determinant(), 1.0);
+        let p = Matrix::<2, 2>::from_row_major_array([[0.0, 1.0], [1.0, 0.0]]);
+        assert_eq!(p.determinant(), -1.0);
+
+        // Near-singular: det = -3 * eps
+        let eps = 1.0e-9;
+        let m = Matrix::<3, 3>::from_row_major_array([
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0 + eps],
+        ]);
+        // Accuracy is limited by representing 9 + eps (ulp ~ 1.8e-15)
+        assert!((m.determinant() + 3.0 * eps).abs() < 1.0e-5 * eps);
+
+        // Exactly singular in theory; floating point leaves only round-off
+        let m = Matrix::<3, 3>::from_row_major_array([
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0],
+        ]);
+        assert!(m.determinant().abs() < 1.0e-14);
+        assert_eq!(Matrix::<3, 3>::zeros().determinant(), 0.0);
+    }
+
+    #[test]
+    fn test_concat_split() {
+        let pos = Vector::<3>::from_vec([1.0, 2.0, 3.0]);
+        let vel = Vector::<3>::from_vec([-4.0, -5.0, -6.0]);
+        let state: Vector<6> = pos.concat(&vel);
+        assert_eq!(
+            state,
+            Vector::<6>::from_vec([1.0, 2.0, 3.0, -4.0, -5.0, -6.0])
+        );
+        let (p, v): (Vector<3>, Vector<3>) = state.split();
+        assert_eq!(p, pos);
+        assert_eq!(v, vel);
+
+        // Uneven parts
+        let (a, b) = state.split::<2, 4>();
+        assert_eq!(a.concat::<4, 6>(&b), state);
+        assert_eq!(b[0], 3.0);
+    }
+
+    #[test]
+    fn test_rank() {
+        let m = Matrix::<3, 3>::from_row_major_array([
+            [2.0, 1.0, 0.0],
+            [1.0, 3.0, 1.0],
+            [0.0, 1.0, 4.0],
+        ]);
+        assert_eq!(m.rank(1.0e-12), 3);
+
+        // Third row is the sum of the first two
+        let m = Matrix::<3, 4>::from_row_major_array([
+            [1.0, 2.0, 3.0, 4.0],
+            [2.0, 0.0, 1.0, 5.0],
+            [3.0, 2.0, 4.0, 9.0],
+        ]);
+        assert_eq!(m.rank(1.0e-12), 2);
+        assert_eq!(m.transpose().rank(1.0e-12), 2);
+
+        assert_eq!(Matrix::<3, 2>::zeros().rank(1.0e-12), 0);
+    }
+
+    #[test]
+    fn test_lu() {
+        let a = Matrix::<3, 3>::from_row_major_array([
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 10.0],
+        ]);
+        let (l, u, perm) = a.lu().unwrap();
+        let mut p = Matrix::<3, 3>::zeros();
+        for (i, &pi) in perm.iter().enumerate() {
+            p[(i, pi)] = 1.0;
+        }
+        assert_eq!(p * a, l * u);
+        for i in 0..3 {
+            assert_eq!(l[(i, i)], 1.0);
+            for j in i + 1..3 {
+                assert_eq!(l[(i, j)], 0.0);
+                assert_eq!(u[(j, i)], 0.0);
+            }
+        }
+
+        // Singular matrix
+        let s = Matrix::<2, 2>::from_row_major_array([[1.0, 2.0], [2.0, 4.0]]);
+        assert!(s.lu().is_none());
+    }
+
+    #[test]
+    fn test_inverse() {
+        let a = Matrix::<3, 3>::from_row_major_array([
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 10.0],
+        ]);
+        let ainv = a.inverse().unwrap();
+        let expected = Matrix::<3, 3>::from_row_major_array([
+            [-2.0 / 3.0, -4.0 / 3.0, 1.0],
+            [-2.0 / 3.0, 11.0 / 3.0, -2.0],
+            [1.0, -2.0, 1.0],
+        ]);
+        assert_close(&ainv, &expected, 1.0e-12);
+        assert_close(&(a * ainv), &Matrix::<3, 3>::identity(), 1.0e-12);
+    }
+
+    #[test]
+    fn test_assign_ops() {
+        let a = Matrix::<2, 3>::from_row_major_array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b = Matrix::<2, 3>::from_row_major_array([[0.5, -1.0, 2.0], [3.0, 0.0, -6.0]]);
+
+        let mut by_value = a;
+        by_value += b;
+        let mut by_ref = a;
+        by_ref += &b;
+        assert_eq!(by_ref, by_value);
+
+        by_value -= b;
+        by_ref -= &b;
+        assert_eq!(by_ref, by_value);
+        assert_eq!(by_ref, a);
+    }
+
+    #[test]
+    fn test_frobenius_orthogonal() {
+        let a = Matrix::<2, 3>::from_row_major_array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b = Matrix::<2, 3>::from_row_major_array([[0.5, -1.0, 2.0], [1.0, 0.0, -3.0]]);
+        assert_eq!(a.frobenius_inner(&b), (a.transpose() * b).trace());
+        assert_eq!(a.frobenius_inner(&b), b.frobenius_inner(&a));
+
+        let axis = Vector::<3>::from_vec([1.0, -2.0, 0.5]);
+        let r = crate::Quaternion::from_axis_angle(&(axis / axis.norm()), 1.2).as_dcm();
+        assert!(r.is_orthogonal(1.0e-12));
+        assert!(!(1.001 * r).is_orthogonal(1.0e-6));
+        assert!((1.001 * r).is_orthogonal(1.0e-2));
+    }
+
+    #[test]
+    fn test_symmetric_antisymmetric_part() {
+        let a = Matrix::<3, 3>::from_row_major_array([
+            [1.0, 2.0, -3.0],
+            [0.5, 4.0, 6.0],
+            [7.0, -1.0, 2.5],
+        ]);
+        let s = a.symmetric_part();
+        let w = a.antisymmetric_part();
+        assert_eq!(s, s.transpose());
+        assert_eq!(w, w.transpose() * -1.0);
+        assert_eq!(s + w, a);
+        assert_eq!(w.trace(), 0.0);
+
+        // A skew-symmetric cross-product matrix has no symmetric part
+        let k = Vector::<3>::from_vec([1.0, 2.0, 3.0]).skew();
+        assert_eq!(k.antisymmetric_part(), k);
+        assert!(k.symmetric_part().is_zero(0.0));
+    }
+
+    #[test]
+    fn test_any_all() {
+        let m = Matrix::<2, 3>::from_row_major_array([[0.0, 1.5, 2.0], [3.0, 0.25, 7.0]]);
+        assert!(m.all(|x| x >= 0.0));
+        assert!(!m.all(|x| x > 0.0));
+        assert!(m.any(|x| x > 5.0));
+        assert!(!m.any(|x| x.is_nan()));
+        assert_eq!(m.count_greater(1.0), 4);
+        assert_eq!(m.count_greater(7.0), 0);
+
+        let mut m = m;
+        m[(1, 1)] = -1.0e-9;
+        assert!(!m.all(|x| x >= 0.0));
+        assert!(m.any(|x| x < 0.0));
+    }
+
+    #[test]
+    fn test_is_finite_is_zero() {
+        let mut m = Matrix::<3, 2>::from_row_major_array([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        assert!(m.is_finite());
+        assert!(!m.is_zero(1.0));
+        m[(2, 1)] = f64::NAN;
+        assert!(!m.is_finite());
+        assert!(!m.is_zero(f64::INFINITY));
+        m[(2, 1)] = f64::NEG_INFINITY;
+        assert!(!m.is_finite());
+
+        let v = Vector::<3>::from_vec([1.0e-13, -2.0e-13, 0.0]);
+        assert!(v.is_finite());
+        assert!(v.is_zero(1.0e-12));
+        assert!(!v.is_zero(1.0e-13));
+        assert!(Matrix::<2, 2>::zeros().is_zero(0.0));
+    }
+
+    #[test]
+    fn test_sqrtm() {
+        // Sample covariance of a few correlated states
+        let p = Matrix::<3, 3>::from_row_major_array([
+            [4.0, 1.2, -0.6],
+            [1.2, 2.5, 0.3],
+            [-0.6, 0.3, 0.9],
+        ]);
+        let s = p.sqrtm().unwrap();
+        assert_close(&(s * s), &p, 1.0e-12);
+        assert_close(&s, &s.transpose(), 1.0e-12);
+
+        let indefinite = Matrix::<2, 2>::from_row_major_array([[1.0, 2.0], [2.0, 1.0]]);
+        assert!(matches!(
+            indefinite.sqrtm(),
+            Err(SCError::NonPositiveDefiniteMatrix)
+        ));
+    }
+
+    #[test]
+    fn test_quadratic_form() {
+        let a = Matrix::<2, 2>::from_row_major_array([[4.0, -1.0], [2.0, 3.0]]);
+        let x = Vector::<2>::from_vec([2.0, -1.0]);
+        // 4*4 - 1*2*(-1) + 2*(-1)*2 + 3*1
+        assert_eq!(x.quadratic_form(&a), 17.0);
+        assert_eq!(x.quadratic_form(&a.symmetric_part()), 17.0);
+        assert_eq!(x.quadratic_form(&Matrix::identity()), x.normsq());
+
+        let y = Vector::<2>::from_vec([1.0, 3.0]);
+        // [2, -1] . [4 - 3, 2 + 9]
+        assert_eq!(x.bilinear(&a, &y), -9.0);
+        assert_eq!(y.bilinear(&a.transpose(), &x), -9.0);
+    }
+
+    #[test]
+    fn test_dot_accurate() {
+        // Large terms cancel, so the naive sum loses the small ones
+        let v = Vector::<6>::from_vec([1.0e17, 3.0, -1.0e17, 0.25, 1.0e-3, 2.0]);
+        let ones = Vector::<6>::from_vec([1.0; 6]);
+        let truth = 5.251;
+        assert!((v.dot(&ones) - truth).abs() > 1.0);
+        assert!((v.dot_accurate(&ones) - truth).abs() < 1.0e-15);
+
+        // Products whose rounding errors matter: (1 + e)(1 - e) - 1 = -e^2
+        let e = 2.0_f64.powi(-30);
+        let a = Vector::<2>::from_vec([1.0 + e, -1.0]);
+        let b = Vector::<2>::from_vec([1.0 - e, 1.0]);
+        assert_eq!(a.dot(&b), 0.0);
+        assert_eq!(a.dot_accurate(&b), -e * e);
+
+        // Many small terms accumulated onto a large one
+        let mut v = Vector::<1001>::from_vec([0.1; 1001]);
+        v[0] = 1.0e8;
+        let naive = v.dot(&Vector::<1001>::from_vec([1.0; 1001]));
+        let accurate = v.dot_accurate(&Vector::<1001>::from_vec([1.0; 1001]));
+        assert!((accurate - (1.0e8 + 100.0)).abs() < (naive - (1.0e8 + 100.0)).abs());
+        assert!((accurate - (1.0e8 + 100.0)).abs() < 1.0e-8);
+
+        let v = Vector::<3>::from_vec([3.0, 4.0, 12.0]);
+        assert_eq!(v.norm_accurate(), 13.0);
+    }
+
+    #[test]
+    fn test_reflect() {
+        let v = Vector::<3>::from_vec([1.0, -2.0, 3.0]);
+        // Across the xy-plane; the normal need not be unit length
+        assert_eq!(
+            v.reflect(&Vector::<3>::from_vec([0.0, 0.0, 5.0])),
+            Vector::<3>::from_vec([1.0, -2.0, -3.0])
+        );
+        let n = Vector::<3>::from_vec([1.0, 1.0, -0.5]);
+        let r = v.reflect(&n);
+        assert!((r.norm() - v.norm()).abs() < 1.0e-14);
+        assert!((r.dot(&n) + v.dot(&n)).abs() < 1.0e-14);
+        assert_close(&r.reflect(&n), &v, 1.0e-14);
+        assert_eq!(v.reflect(&Vector::<3>::zeros()), v);
+    }
+
+    #[test]
+    fn test_skew_rotate_about() {
+        let v = Vector::<3>::from_vec([0.3, -1.0, 2.5]);
+        let u = Vector::<3>::from_vec([1.5, 0.2, -0.7]);
+        assert_eq!(v.skew() * u, v.cross(&u));
+        assert_eq!(v.skew().transpose(), -1.0 * v.skew());
+
+        let r = Vector::<3>::xhat().rotate_about(&Vector::<3>::zhat(), std::f64::consts::FRAC_PI_2);
+        assert_eq!(r, Vector::<3>::yhat());
+
+        // Agrees with the quaternion rotation; the axis need not be unit length
+        let axis = Vector::<3>::from_vec([2.0, -1.0, 0.5]);
+        let q = crate::Quaternion::from_axis_angle(&(axis / axis.norm()), 0.8);
+        assert!((v.rotate_about(&axis, 0.8) - q * v).norm() < 1.0e-12);
+        assert_eq!(v.rotate_about(&Vector::<3>::zeros(), 0.8), v);
+    }
+
     #[test]
     fn test_cross_product() {
         // Test cross product follows right-handed convention
@@ -1054,4 +2634,175 @@ mod tests {
         assert!(Vector3::yhat().cross(&Vector3::zhat()) == Vector3::xhat());
         assert!(Vector3::zhat().cross(&Vector3::xhat()) == Vector3::yhat());
     }
+
+    #[test]
+    fn test_spherical() {
+        type Vector3 = Vector<3>;
+        for v in [
+            [1.0, 2.0, 3.0],
+            [-1.0, 0.5, -2.0],
+            [0.0, -4.0, 0.1],
+            [-3.0, -1.0e-3, 0.0],
+            [5.0, 0.0, 0.0],
+        ] {
+            let v = Vector3::from_vec(v);
+            let (r, theta, phi) = v.to_spherical();
+            assert!((0.0..=std::f64::consts::PI).contains(&theta));
+            assert_close(&Vector3::from_spherical(r, theta, phi), &v, 1.0e-12);
+        }
+
+        // Poles and origin give a stable azimuth
+        assert_eq!(Vector3::zhat().to_spherical(), (1.0, 0.0, 0.0));
+        let (r, theta, phi) = (-2.0 * Vector3::zhat()).to_spherical();
+        assert_eq!((r, theta, phi), (2.0, std::f64::consts::PI, 0.0));
+        assert_eq!(Vector3::zeros().to_spherical(), (0.0, 0.0, 0.0));
+        assert_eq!((-1.0 * Vector3::zeros()).to_spherical(), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_remove_insert() {
+        let h = Matrix::<3, 2>::from_row_major_array([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let h2: Matrix<2, 2> = h.remove_row(1);
+        assert_eq!(
+            h2,
+            Matrix::<2, 2>::from_row_major_array([[1.0, 2.0], [5.0, 6.0]])
+        );
+        let first: Matrix<2, 2> = h.remove_row(0);
+        assert_eq!(first.row(0), h.row(1));
+        let last: Matrix<2, 2> = h.remove_row(2);
+        assert_eq!(last.row(1), h.row(1));
+
+        // Reinserting the removed row restores the original
+        let back: Matrix<3, 2> = h2.insert_row(1, &h.row(1));
+        assert_eq!(back, h);
+
+        let c: Matrix<3, 1> = h.remove_column(0);
+        assert_eq!(c.column(0), h.column(1));
+        let back: Matrix<3, 2> = c.insert_column(0, &h.column(0));
+        assert_eq!(back, h);
+        let wide: Matrix<3, 3> = h.insert_column(2, &Vector::<3>::from_vec([7.0, 8.0, 9.0]));
+        assert_eq!(wide.row(2), Vector::<3>::from_vec([5.0, 6.0, 9.0]));
+    }
+
+    #[test]
+    fn test_csv() {
+        let m = Matrix::<2, 3>::from_row_major_array([
+            [1.0, -2.5, std::f64::consts::PI],
+            [1.0e-300, 6.02e23, 0.1 + 0.2],
+        ]);
+        let csv = m.to_csv();
+        assert_eq!(csv.lines().count(), 2);
+        let back = Matrix::<2, 3>::from_csv(&csv).unwrap();
+        assert_eq!(back.as_flat_slice(), m.as_flat_slice());
+
+        let v = Vector::<3>::from_vec([1.0, 2.0, 3.0]);
+        assert_eq!(v.to_csv(), "1\n2\n3\n");
+        assert_eq!(Vector::<3>::from_csv(&v.to_csv()).unwrap(), v);
+        let crlf = Matrix::<2, 2>::from_csv(" 1 , 2\r\n3,4\r\n\n").unwrap();
+        assert_eq!(
+            crlf,
+            Matrix::<2, 2>::from_row_major_array([[1.0, 2.0], [3.0, 4.0]])
+        );
+
+        // Wrong dimensions and bad values
+        for bad in [
+            "1,2\n3,4\n",
+            "1,2,3\n4,5\n",
+            "1,2,3\n",
+            "1,2,3\n4,5,6\n7,8,9\n",
+            "1,2,x\n4,5,6",
+        ] {
+            assert!(matches!(
+                Matrix::<2, 3>::from_csv(bad),
+                Err(SCError::InvalidInput)
+            ));
+        }
+        assert!(Vector::<3>::from_csv("1,2,3").is_err());
+    }
+
+    #[test]
+    fn test_norm_variants() {
+        let v = Vector::<3>::from_vec([3.0, -4.0, 0.0]);
+        assert_eq!(v.norm_l1(), 7.0);
+        assert_eq!(v.norm_inf(), 4.0);
+        assert!((v.norm_p(1.0) - 7.0).abs() < 1.0e-15);
+        assert!((v.norm_p(2.0) - 5.0).abs() < 1.0e-15);
+        // (27 + 64)^(1/3)
+        assert!((v.norm_p(3.0) - 91.0_f64.cbrt()).abs() < 1.0e-14);
+        assert_eq!(v.norm_p(f64::INFINITY), 4.0);
+        assert!((v.norm_p(500.0) - 4.0).abs() < 1.0e-3);
+
+        // No overflow for large elements and orders
+        let big = Vector::<2>::from_vec([1.0e300, -1.0e300]);
+        assert!((big.norm_p(4.0) / 1.0e300 - 2.0_f64.powf(0.25)).abs() < 1.0e-14);
+        assert_eq!(Vector::<3>::zeros().norm_p(2.0), 0.0);
+        assert_eq!(Vector::<3>::zeros().norm_inf(), 0.0);
+
+        // NaN propagates wherever it is
+        let nan = Vector::<2>::from_vec([f64::NAN, 1.0]);
+        assert!(nan.norm_inf().is_nan());
+        assert!(nan.norm_p(f64::INFINITY).is_nan());
+        assert!(nan.norm_p(3.0).is_nan());
+        assert!(Vector::<2>::from_vec([1.0, f64::NAN]).norm_inf().is_nan());
+    }
+
+    #[test]
+    fn test_radec() {
+        use std::f64::consts::{FRAC_PI_2, PI};
+        // Vernal equinox and celestial poles
+        assert_eq!(Vector::<3>::xhat().to_radec(), (0.0, 0.0));
+        assert_eq!((Vector::<3>::xhat() * 7.0).to_radec(), (0.0, 0.0));
+        assert_eq!(Vector::<3>::zhat().to_radec(), (0.0, FRAC_PI_2));
+        assert_eq!((Vector::<3>::zhat() * -2.0).to_radec(), (0.0, -FRAC_PI_2));
+        assert_eq!(Vector::<3>::from_radec(0.0, 0.0), Vector::<3>::xhat());
+        assert_eq!(
+            Vector::<3>::from_radec(1.234, FRAC_PI_2),
+            Vector::<3>::zhat()
+        );
+
+        // Right ascension wraps to [0, 2π)
+        let (ra, dec) = Vector::<3>::from_vec([-1.0, -1.0, 0.0]).to_radec();
+        assert!((ra - 1.25 * PI).abs() < 1.0e-15 && dec == 0.0);
+
+        for (ra, dec) in [(0.1, -1.2), (3.0, 0.4), (6.2, 1.5), (4.0, -0.01)] {
+            let v = Vector::<3>::from_radec(ra, dec);
+            assert!((v.norm() - 1.0).abs() < 1.0e-15);
+            let (ra2, dec2) = v.to_radec();
+            assert!((ra2 - ra).abs() < 1.0e-14 && (dec2 - dec).abs() < 1.0e-14);
+        }
+    }
+
+    #[test]
+    fn test_dominant_eigenvalue() {
+        // Symmetric, with eigenvalues 1, 3, and 4
+        let a = Matrix::<3, 3>::from_row_major_array([
+            [3.0, -1.0, 0.0],
+            [-1.0, 2.0, -1.0],
+            [0.0, -1.0, 3.0],
+        ]);
+        let (lambda, v) = a.dominant_eigenvalue(200, 1.0e-10).unwrap();
+        assert!((lambda - 4.0).abs() < 1.0e-12);
+        let expected = Vector::<3>::from_vec([1.0, -1.0, 1.0]) / 3.0_f64.sqrt();
+        assert!((v - expected).norm() < 1.0e-9 || (v + expected).norm() < 1.0e-9);
+
+        // Nonsymmetric, with a negative dominant eigenvalue: -5 and 2
+        let b = Matrix::<2, 2>::from_row_major_array([[-4.0, 3.0], [2.0, 1.0]]);
+        let (lambda, v) = b.dominant_eigenvalue(500, 1.0e-12).unwrap();
+        assert!((lambda + 5.0).abs() < 1.0e-10);
+        assert!((b * v - v * lambda).norm() < 1.0e-10);
+
+        // Rotation: complex eigenvalues never converge
+        let r = Matrix::<2, 2>::from_row_major_array([[0.0, -1.0], [1.0, 0.0]]);
+        assert!(matches!(
+            r.dominant_eigenvalue(100, 1.0e-12),
+            Err(SCError::NotConverged)
+        ));
+        assert_eq!(
+            Matrix::<2, 2>::zeros()
+                .dominant_eigenvalue(1, 1.0e-12)
+                .unwrap()
+                .0,
+            0.0
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use crate::Matrix;
+use crate::{Matrix, Matrix3, Vector};
 use crate::{SCError, SCResult};
 
 /// Cholesky decomposition
@@ -13,7 +13,7 @@ use crate::{SCError, SCResult};
 ///
 /// # Example
 /// ```
-/// use satctrl::matutils::cholesky_decomp;
+/// use satctrl::matrixutils::cholesky_decomp;
 /// use satctrl::Matrix3;
 /// let a = Matrix3::from_row_major_array([[25.0, 15.0, -5.0], [15.0, 18.0, 0.0], [-5.0, 0.0, 11.0]]);
 /// let l = cholesky_decomp(&a);
@@ -40,10 +40,416 @@ pub fn cholesky_decomp<const N: usize>(a: &Matrix<N, N>) -> SCResult<Matrix<N, N
     Ok(l)
 }
 
+/// Cholesky rank-1 update or downdate
+///
+/// Given the lower-triangular Cholesky factor `l` of `A`, modify it in place
+/// to be the Cholesky factor of `A + sign * x * xᵀ`, without refactoring
+///
+/// # Arguments
+/// * `l` - The lower-triangular Cholesky factor, updated in place
+/// * `x` - The rank-1 update vector
+/// * `sign` - +1.0 for an update, -1.0 for a downdate
+///
+/// # Returns
+/// `SCError::NonPositiveDefiniteMatrix` if a downdate would make the
+/// matrix indefinite, in which case `l` is left unchanged
+///
+/// # Example
+/// ```
+/// use satctrl::matrixutils::{cholesky_decomp, chol_update};
+/// use satctrl::{Matrix3, Vector3};
+/// let a = Matrix3::from_row_major_array([[25.0, 15.0, -5.0], [15.0, 18.0, 0.0], [-5.0, 0.0, 11.0]]);
+/// let mut l = cholesky_decomp(&a).unwrap();
+/// chol_update(&mut l, &Vector3::from_vec([1.0, 2.0, 3.0]), 1.0).unwrap();
+/// ```
+///
+pub fn chol_update<const M: usize>(l: &mut Matrix<M, M>, x: &Vector<M>, sign: f64) -> SCResult<()> {
+    let mut lnew = *l;
+    let mut x = *x;
+    for k in 0..M {
+        let r2 = lnew[(k, k)] * lnew[(k, k)] + sign * x[k] * x[k];
+        if r2 <= 0.0 {
+            return Err(SCError::NonPositiveDefiniteMatrix);
+        }
+        let r = r2.sqrt();
+        let c = r / lnew[(k, k)];
+        let s = x[k] / lnew[(k, k)];
+        lnew[(k, k)] = r;
+        for i in k + 1..M {
+            lnew[(i, k)] = (lnew[(i, k)] + sign * s * x[i]) / c;
+            x[i] = c * x[i] - s * lnew[(i, k)];
+        }
+    }
+    *l = lnew;
+    Ok(())
+}
+
+/// Solve `l * x = b` for lower-triangular `l` by forward substitution
+///
+/// # Arguments
+/// * `l` - Lower-triangular matrix
+/// * `b` - Right-hand side
+///
+/// # Returns
+/// The solution vector `x`
+///
+/// # Notes
+/// * Triangularity is not checked; elements above the diagonal are ignored
+/// * A zero on the diagonal produces non-finite output
+///
+/// # Example
+/// ```
+/// use satctrl::matrixutils::forward_substitution;
+/// use satctrl::{Matrix2, Vector2};
+/// let l = Matrix2::from_row_major_array([[2.0, 0.0], [1.0, 1.0]]);
+/// let x = forward_substitution(&l, &Vector2::from_vec([2.0, 3.0]));
+/// assert_eq!(x, Vector2::from_vec([1.0, 2.0]));
+/// ```
+///
+pub fn forward_substitution<const M: usize>(l: &Matrix<M, M>, b: &Vector<M>) -> Vector<M> {
+    let mut x = *b;
+    for i in 0..M {
+        for k in 0..i {
+            x[i] -= l[(i, k)] * x[k];
+        }
+        x[i] /= l[(i, i)];
+    }
+    x
+}
+
+/// Solve `u * x = b` for upper-triangular `u` by back substitution
+///
+/// # Arguments
+/// * `u` - Upper-triangular matrix
+/// * `b` - Right-hand side
+///
+/// # Returns
+/// The solution vector `x`
+///
+/// # Notes
+/// * Triangularity is not checked; elements below the diagonal are ignored
+/// * A zero on the diagonal produces non-finite output
+///
+/// # Example
+/// ```
+/// use satctrl::matrixutils::back_substitution;
+/// use satctrl::{Matrix2, Vector2};
+/// let u = Matrix2::from_row_major_array([[1.0, 1.0], [0.0, 2.0]]);
+/// let x = back_substitution(&u, &Vector2::from_vec([3.0, 4.0]));
+/// assert_eq!(x, Vector2::from_vec([1.0, 2.0]));
+/// ```
+///
+pub fn back_substitution<const M: usize>(u: &Matrix<M, M>, b: &Vector<M>) -> Vector<M> {
+    let mut x = *b;
+    for i in (0..M).rev() {
+        for k in i + 1..M {
+            x[i] -= u[(i, k)] * x[k];
+        }
+        x[i] /= u[(i, i)];
+    }
+    x
+}
+
+/// Solve a tridiagonal linear system with the Thomas algorithm
+///
+/// Solves `A x = rhs` in O(n) operations, where `A` has `diag` on its
+/// main diagonal, `sub` just below it, and `sup` just above it. No
+/// pivoting is done, so the system should be diagonally dominant or
+/// otherwise known to be stable without it, as for spline fitting
+///
+/// # Arguments
+/// * `sub` - The n - 1 elements below the diagonal, `A[i + 1][i]`
+/// * `diag` - The n diagonal elements
+/// * `sup` - The n - 1 elements above the diagonal, `A[i][i + 1]`
+/// * `rhs` - The n elements of the right-hand side
+///
+/// # Returns
+/// The solution `x`, `SCError::InvalidInput` if the lengths are
+/// inconsistent or `diag` is empty, or `SCError::MatrixIsSingular` if a
+/// pivot is zero
+///
+/// # Example
+/// ```
+/// use satctrl::matrixutils::solve_tridiagonal;
+/// let x = solve_tridiagonal(&[1.0, 1.0], &[4.0, 4.0, 4.0], &[1.0, 1.0], &[5.0, 6.0, 5.0]).unwrap();
+/// assert!(x.iter().all(|xi| (xi - 1.0).abs() < 1.0e-12));
+/// ```
+///
+pub fn solve_tridiagonal(
+    sub: &[f64],
+    diag: &[f64],
+    sup: &[f64],
+    rhs: &[f64],
+) -> SCResult<Vec<f64>> {
+    let n = diag.len();
+    if n == 0 || sub.len() != n - 1 || sup.len() != n - 1 || rhs.len() != n {
+        return Err(SCError::InvalidInput);
+    }
+
+    // Forward sweep, eliminating the subdiagonal
+    let mut c = vec![0.0; n];
+    let mut x = vec![0.0; n];
+    let mut pivot = diag[0];
+    for i in 0..n {
+        if i > 0 {
+            pivot = diag[i] - sub[i - 1] * c[i - 1];
+        }
+        if pivot == 0.0 {
+            return Err(SCError::MatrixIsSingular);
+        }
+        if i + 1 < n {
+            c[i] = sup[i] / pivot;
+        }
+        let prev = if i > 0 { sub[i - 1] * x[i - 1] } else { 0.0 };
+        x[i] = (rhs[i] - prev) / pivot;
+    }
+
+    // Back substitution
+    for i in (0..n - 1).rev() {
+        x[i] -= c[i] * x[i + 1];
+    }
+    Ok(x)
+}
+
+/// Householder reflector that zeros all but the first element of a vector
+///
+/// The reflector is `H = I - beta v vᵀ`, which is symmetric and
+/// orthogonal. The sign of `v[0]` is chosen to avoid cancellation, so
+/// `H x = alpha e₁` with `alpha = -sign(x[0]) |x|`
+///
+/// # Arguments
+/// * `x` - The vector to reflect
+///
+/// # Returns
+/// The reflector vector `v` and the scale `beta = 2 / (vᵀv)`. For a zero
+/// vector, `v` is zero and `beta` is zero, so `H` is the identity
+///
+/// # Example
+/// ```
+/// use satctrl::matrixutils::householder;
+/// use satctrl::Vector3;
+/// let x = Vector3::from_vec([3.0, 0.0, 4.0]);
+/// let (v, beta) = householder(&x);
+/// let hx = x - v * (beta * v.dot(&x));
+/// assert!((hx[0] + 5.0).abs() < 1.0e-12 && hx[2].abs() < 1.0e-12);
+/// ```
+///
+pub fn householder<const M: usize>(x: &Vector<M>) -> (Vector<M>, f64) {
+    let norm = x.norm();
+    if norm == 0.0 {
+        return (Vector::<M>::zeros(), 0.0);
+    }
+    let alpha = if x[0] > 0.0 { -norm } else { norm };
+    let mut v = *x;
+    v[0] -= alpha;
+    (v, 2.0 / v.normsq())
+}
+
+/// Givens rotation that zeros the second element of a 2-vector
+///
+/// # Arguments
+/// * `a` - The first element
+/// * `b` - The element to zero
+///
+/// # Returns
+/// The cosine and sine `(c, s)` such that
+/// `[[c, s], [-s, c]] * [a, b]ᵀ = [r, 0]ᵀ` with `r = hypot(a, b)`.
+/// Returns `(1, 0)` if both elements are zero
+///
+/// # Example
+/// ```
+/// use satctrl::matrixutils::givens;
+/// let (c, s) = givens(3.0, 4.0);
+/// assert!((c * 3.0 + s * 4.0 - 5.0).abs() < 1.0e-12);
+/// assert!((-s * 3.0 + c * 4.0).abs() < 1.0e-12);
+/// ```
+///
+pub fn givens(a: f64, b: f64) -> (f64, f64) {
+    let r = a.hypot(b);
+    if r == 0.0 {
+        (1.0, 0.0)
+    } else {
+        (a / r, b / r)
+    }
+}
+
+/// Eigen-decomposition of a real symmetric matrix
+///
+/// Uses the cyclic Jacobi method, which is robust and accurate for the
+/// small matrices used in this crate. Only symmetric input is supported;
+/// the strict upper and lower triangles are assumed equal
+///
+/// # Arguments
+/// * `a` - The symmetric input matrix
+///
+/// # Returns
+/// The eigenvalues in ascending order and a matrix whose columns are the
+/// corresponding unit eigenvectors, or `SCError::NotConverged`
+///
+/// # Example
+/// ```
+/// use satctrl::matrixutils::symmetric_eigen;
+/// use satctrl::Matrix2;
+/// let a = Matrix2::from_row_major_array([[2.0, 1.0], [1.0, 2.0]]);
+/// let (d, v) = symmetric_eigen(&a).unwrap();
+/// assert!((d[0] - 1.0).abs() < 1.0e-12 && (d[1] - 3.0).abs() < 1.0e-12);
+/// ```
+///
+pub fn symmetric_eigen<const N: usize>(a: &Matrix<N, N>) -> SCResult<(Vector<N>, Matrix<N, N>)> {
+    let mut a = *a;
+    let mut v = Matrix::<N, N>::identity();
+    let mut scale = 0.0;
+    for i in 0..N {
+        for j in 0..N {
+            scale += a[(i, j)] * a[(i, j)];
+        }
+    }
+
+    let mut converged = false;
+    for _sweep in 0..50 {
+        let mut off = 0.0;
+        for p in 0..N {
+            for q in p + 1..N {
+                off += a[(p, q)] * a[(p, q)];
+            }
+        }
+        if off <= f64::EPSILON * f64::EPSILON * scale {
+            converged = true;
+            break;
+        }
+        for p in 0..N {
+            for q in p + 1..N {
+                if a[(p, q)] == 0.0 {
+                    continue;
+                }
+                // Rotation that zeros the (p, q) element
+                let theta = (a[(q, q)] - a[(p, p)]) / (2.0 * a[(p, q)]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                for k in 0..N {
+                    let (akp, akq) = (a[(k, p)], a[(k, q)]);
+                    a[(k, p)] = c * akp - s * akq;
+                    a[(k, q)] = s * akp + c * akq;
+                }
+                for k in 0..N {
+                    let (apk, aqk) = (a[(p, k)], a[(q, k)]);
+                    a[(p, k)] = c * apk - s * aqk;
+                    a[(q, k)] = s * apk + c * aqk;
+                }
+                for k in 0..N {
+                    let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+                    v[(k, p)] = c * vkp - s * vkq;
+                    v[(k, q)] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+    if !converged {
+        return Err(SCError::NotConverged);
+    }
+
+    // Sort eigenvalues (and their vectors) in ascending order
+    let mut order: [usize; N] = std::array::from_fn(|i| i);
+    order.sort_by(|&i, &j| a[(i, i)].total_cmp(&a[(j, j)]));
+    let mut d = Vector::<N>::zeros();
+    let mut vsorted = Matrix::<N, N>::zeros();
+    for (i, &oi) in order.iter().enumerate() {
+        d[i] = a[(oi, oi)];
+        for k in 0..N {
+            vsorted[(k, i)] = v[(k, oi)];
+        }
+    }
+    Ok((d, vsorted))
+}
+
+/// 3-2-1 (yaw, pitch, roll) Euler angles from a direction cosine matrix
+///
+/// The DCM is interpreted as the active rotation `Rz(yaw) * Ry(pitch) * Rx(roll)`,
+/// matching `Quaternion::from_rpy` and `Quaternion::as_dcm`
+///
+/// # Arguments
+/// * `m` - The 3x3 direction cosine matrix
+///
+/// # Returns
+/// A tuple of roll, pitch, yaw in radians
+///
+/// # Notes
+/// * The pitch sine is clamped to [-1, 1] so round-off cannot produce NaN
+/// * At pitch = ±90° roll and yaw are not separable; roll is set to zero
+///   and the combined rotation is returned in yaw
+///
+/// # Example
+/// ```
+/// use satctrl::matrixutils::dcm_to_euler321;
+/// use satctrl::Quaternion;
+/// let dcm = Quaternion::from_rpy(0.1, 0.2, 0.3).as_dcm();
+/// let (roll, pitch, yaw) = dcm_to_euler321(&dcm);
+/// assert!((roll - 0.1).abs() < 1.0e-12);
+/// ```
+///
+pub fn dcm_to_euler321(m: &Matrix3) -> (f64, f64, f64) {
+    let pitch = (-m[(2, 0)]).clamp(-1.0, 1.0).asin();
+    if m[(2, 1)].hypot(m[(2, 2)]) < 1.0e-10 {
+        // Gimbal lock: with roll = 0, the upper-left 2x2 block of the
+        // remaining rows gives the yaw
+        let yaw = (-m[(0, 1)]).atan2(m[(1, 1)]);
+        return (0.0, pitch, yaw);
+    }
+    let roll = m[(2, 1)].atan2(m[(2, 2)]);
+    let yaw = m[(1, 0)].atan2(m[(0, 0)]);
+    (roll, pitch, yaw)
+}
+
+/// Direction cosine matrix from a sequence of single-axis rotations
+///
+/// The matrix is the active rotation
+/// `R(axes[0], angles[0]) * R(axes[1], angles[1]) * ...`, i.e. the
+/// rotations are applied to a vector starting from the *last* element of
+/// the sequence. Equivalently, each rotation is about an axis of the frame
+/// already rotated by the preceding elements (intrinsic rotations), so
+/// `(&[yaw, pitch, roll], &['z', 'y', 'x'])` is the 3-2-1 sequence of
+/// `Quaternion::from_rpy` and `dcm_to_euler321`
+///
+/// # Arguments
+/// * `angles` - The rotation angles in radians, right-handed
+/// * `axes` - The axis of each rotation: 'x', 'y', or 'z' (either case)
+///
+/// # Returns
+/// The 3x3 rotation matrix, or `SCError::InvalidInput` if the lengths
+/// differ or an axis is not recognized
+///
+/// # Example
+/// ```
+/// use satctrl::matrixutils::dcm_sequence;
+/// use satctrl::{Matrix3, Quaternion};
+/// let dcm = dcm_sequence(&[0.3, 0.2, 0.1], &['z', 'y', 'x']).unwrap();
+/// let expected = Quaternion::from_rpy(0.1, 0.2, 0.3).as_dcm();
+/// assert!((dcm - expected).as_flat_slice().iter().all(|e| e.abs() < 1.0e-15));
+/// ```
+///
+pub fn dcm_sequence(angles: &[f64], axes: &[char]) -> SCResult<Matrix3> {
+    if angles.len() != axes.len() {
+        return Err(SCError::InvalidInput);
+    }
+    let mut dcm = Matrix3::identity();
+    for (angle, axis) in angles.iter().zip(axes.iter()) {
+        let (s, c) = angle.sin_cos();
+        let r = match axis.to_ascii_lowercase() {
+            'x' => Matrix3::from_row_major_array([[1.0, 0.0, 0.0], [0.0, c, -s], [0.0, s, c]]),
+            'y' => Matrix3::from_row_major_array([[c, 0.0, s], [0.0, 1.0, 0.0], [-s, 0.0, c]]),
+            'z' => Matrix3::from_row_major_array([[c, -s, 0.0], [s, c, 0.0], [0.0, 0.0, 1.0]]),
+            _ => return Err(SCError::InvalidInput),
+        };
+        dcm = dcm * r;
+    }
+    Ok(dcm)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Matrix3;
+    use crate::{Quaternion, Vector3};
 
     #[test]
     fn test_cholesky_decomp() {
@@ -61,4 +467,230 @@ mod tests {
         assert_eq!(l, l_expected);
         assert_eq!(a, l * l.transpose());
     }
+
+    #[test]
+    fn test_chol_update() {
+        let a = Matrix3::from_row_major_array([
+            [25.0, 15.0, -5.0],
+            [15.0, 18.0, 0.0],
+            [-5.0, 0.0, 11.0],
+        ]);
+        let l0 = cholesky_decomp(&a).unwrap();
+        let x = Vector3::from_vec([1.0, -2.0, 0.5]);
+
+        let mut l = l0;
+        chol_update(&mut l, &x, 1.0).unwrap();
+        let expected = a + x * x.transpose();
+        let actual = l * l.transpose();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((actual[(i, j)] - expected[(i, j)]).abs() < 1.0e-12);
+            }
+        }
+
+        chol_update(&mut l, &x, -1.0).unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((l[(i, j)] - l0[(i, j)]).abs() < 1.0e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_chol_downdate_indefinite() {
+        let mut l = Matrix3::identity();
+        let x = Vector3::from_vec([0.0, 2.0, 0.0]);
+        assert!(matches!(
+            chol_update(&mut l, &x, -1.0),
+            Err(SCError::NonPositiveDefiniteMatrix)
+        ));
+        assert_eq!(l, Matrix3::identity());
+    }
+
+    #[test]
+    fn test_triangular_solve() {
+        let l =
+            Matrix3::from_row_major_array([[2.0, 0.0, 0.0], [-1.0, 4.0, 0.0], [3.0, 0.5, -1.0]]);
+        let x = Vector3::from_vec([1.0, -2.0, 0.5]);
+        let b = l * x;
+        assert_eq!(forward_substitution(&l, &b), x);
+        assert_eq!(back_substitution(&l.transpose(), &(l.transpose() * x)), x);
+
+        // Entries outside the triangle are ignored
+        let mut full = l;
+        full[(0, 2)] = 100.0;
+        assert_eq!(forward_substitution(&full, &b), x);
+
+        // Solve a full system through its Cholesky factor
+        let a = l * l.transpose();
+        let c = cholesky_decomp(&a).unwrap();
+        let y = forward_substitution(&c, &(a * x));
+        let xs = back_substitution(&c.transpose(), &y);
+        for i in 0..3 {
+            assert!((xs[i] - x[i]).abs() < 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn test_symmetric_eigen() {
+        let a = Matrix::<4, 4>::from_row_major_array([
+            [4.0, 1.0, -2.0, 2.0],
+            [1.0, 2.0, 0.0, 1.0],
+            [-2.0, 0.0, 3.0, -2.0],
+            [2.0, 1.0, -2.0, -1.0],
+        ]);
+        let (d, v) = symmetric_eigen(&a).unwrap();
+        for i in 1..4 {
+            assert!(d[i - 1] <= d[i]);
+        }
+        let av = a * v;
+        let vd = v * Matrix::<4, 4>::diag_from_vector(&d);
+        let vtv = v.transpose() * v;
+        let eye = Matrix::<4, 4>::identity();
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((av[(i, j)] - vd[(i, j)]).abs() < 1.0e-12);
+                assert!((vtv[(i, j)] - eye[(i, j)]).abs() < 1.0e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dcm_to_euler321() {
+        let dcm = Quaternion::from_rpy(0.3, -0.7, 2.5).as_dcm();
+        let (roll, pitch, yaw) = dcm_to_euler321(&dcm);
+        assert!((roll - 0.3).abs() < 1.0e-12);
+        assert!((pitch + 0.7).abs() < 1.0e-12);
+        assert!((yaw - 2.5).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn test_dcm_to_euler321_singular() {
+        let pi2 = std::f64::consts::FRAC_PI_2;
+        for pitch in [pi2, -pi2] {
+            let dcm = Quaternion::from_rpy(0.4, pitch, 1.1).as_dcm();
+            let (r, p, y) = dcm_to_euler321(&dcm);
+            assert_eq!(r, 0.0);
+            assert!((p - pitch).abs() < 1.0e-6);
+            assert!(y.is_finite());
+            // Recovered angles must reproduce the same rotation
+            let dcm2 = Quaternion::from_rpy(r, p, y).as_dcm();
+            for i in 0..3 {
+                for j in 0..3 {
+                    assert!((dcm[(i, j)] - dcm2[(i, j)]).abs() < 1.0e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dcm_sequence() {
+        // 3-2-1 sequence matches the quaternion Euler-angle constructor
+        let (roll, pitch, yaw) = (0.3, -0.7, 2.5);
+        let dcm = dcm_sequence(&[yaw, pitch, roll], &['z', 'y', 'x']).unwrap();
+        let expected = Quaternion::from_rpy(roll, pitch, yaw).as_dcm();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((dcm[(i, j)] - expected[(i, j)]).abs() < 1.0e-14);
+            }
+        }
+        assert!((dcm_to_euler321(&dcm).2 - yaw).abs() < 1.0e-12);
+
+        // Each element applies its rotation to the vector after the later ones
+        let dcm = dcm_sequence(&[0.4, -1.2, 0.9], &['X', 'z', 'x']).unwrap();
+        let q = Quaternion::rotx(0.4) * Quaternion::rotz(-1.2) * Quaternion::rotx(0.9);
+        let v = Vector3::from_vec([0.5, -1.0, 2.0]);
+        assert!((dcm * v - q * v).norm() < 1.0e-14);
+
+        assert_eq!(dcm_sequence(&[], &[]).unwrap(), Matrix3::identity());
+        assert!(matches!(
+            dcm_sequence(&[0.1, 0.2], &['z']),
+            Err(SCError::InvalidInput)
+        ));
+        assert!(matches!(
+            dcm_sequence(&[0.1], &['w']),
+            Err(SCError::InvalidInput)
+        ));
+    }
+
+    #[test]
+    fn test_householder() {
+        let x = Vector::<4>::from_vec([-1.0, 2.0, -2.0, 4.0]);
+        let (v, beta) = householder(&x);
+        let h = Matrix::<4, 4>::identity() - beta * v * v.transpose();
+        assert_eq!(h * h.transpose(), Matrix::<4, 4>::identity());
+        let hx = h * x;
+        assert!((hx[0] - 5.0).abs() < 1.0e-12);
+        for i in 1..4 {
+            assert!(hx[i].abs() < 1.0e-12);
+        }
+
+        // Already along e₁, with either sign
+        for x0 in [2.0, -2.0] {
+            let x = Vector3::from_vec([x0, 0.0, 0.0]);
+            let (v, beta) = householder(&x);
+            let hx = x - v * (beta * v.dot(&x));
+            assert!((hx[0].abs() - 2.0).abs() < 1.0e-12);
+            assert!(hx[1] == 0.0 && hx[2] == 0.0);
+        }
+        let (v, beta) = householder(&Vector3::zeros());
+        assert_eq!(beta, 0.0);
+        assert_eq!(v, Vector3::zeros());
+    }
+
+    #[test]
+    fn test_givens() {
+        for (a, b) in [(3.0, 4.0), (-1.0, 2.0), (0.0, -5.0), (1.0e-300, 1.0e-300)] {
+            let (c, s) = givens(a, b);
+            assert!((c * c + s * s - 1.0).abs() < 1.0e-14);
+            let r = c * a + s * b;
+            assert!((r - f64::hypot(a, b)).abs() <= 1.0e-14 * r.abs());
+            assert!((-s * a + c * b).abs() <= 1.0e-14 * r.abs());
+        }
+        assert_eq!(givens(0.0, 0.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn test_solve_tridiagonal() {
+        // Compare against the dense solution
+        let sub = [1.0, -2.0, 0.5, 3.0];
+        let diag = [5.0, 6.0, -7.0, 4.0, 8.0];
+        let sup = [2.0, 1.0, -1.0, 0.25];
+        let rhs = [1.0, -2.0, 3.0, 0.5, 4.0];
+        let x = solve_tridiagonal(&sub, &diag, &sup, &rhs).unwrap();
+        let mut a = Matrix::<5, 5>::zeros();
+        for i in 0..5 {
+            a[(i, i)] = diag[i];
+            if i < 4 {
+                a[(i + 1, i)] = sub[i];
+                a[(i, i + 1)] = sup[i];
+            }
+        }
+        let expected = a.inverse().unwrap() * Vector::<5>::from_vec(rhs);
+        for i in 0..5 {
+            assert!((x[i] - expected[i]).abs() < 1.0e-12);
+        }
+        assert_eq!(
+            solve_tridiagonal(&[], &[2.0], &[], &[3.0]).unwrap(),
+            vec![1.5]
+        );
+
+        // Zero pivots, at the start and after elimination
+        assert!(matches!(
+            solve_tridiagonal(&[1.0], &[0.0, 1.0], &[1.0], &[1.0, 1.0]),
+            Err(SCError::MatrixIsSingular)
+        ));
+        assert!(matches!(
+            solve_tridiagonal(&[1.0], &[1.0, 1.0], &[1.0], &[1.0, 2.0]),
+            Err(SCError::MatrixIsSingular)
+        ));
+        assert!(matches!(
+            solve_tridiagonal(&[1.0, 1.0], &[1.0, 1.0], &[1.0], &[1.0, 2.0]),
+            Err(SCError::InvalidInput)
+        ));
+        assert!(matches!(
+            solve_tridiagonal(&[], &[], &[], &[]),
+            Err(SCError::InvalidInput)
+        ));
+    }
 }
@@ -1,13 +1,34 @@
+mod angles;
+mod integrator;
 mod matrix;
+mod odestate;
 mod quaternion;
 mod rk4;
+mod rk45;
+mod rk8;
+mod rktableau;
+mod symmetric;
 
+pub use angles::{wrap_180, wrap_2pi, wrap_360, wrap_pi, Angle};
 pub use matrix::Matrix;
 pub use matrix::Vector;
 pub use quaternion::Quaternion;
+pub use symmetric::{
+    SymmetricMatrix, SymmetricMatrix2, SymmetricMatrix3, SymmetricMatrix4, SymmetricMatrix5,
+    SymmetricMatrix6,
+};
 
+pub use integrator::{IntegrationMethod, Integrator};
+pub use odestate::{CompositeState, ODEState};
 pub use rk4::rk4_integrate;
+pub use rk4::rk4_integrate_at;
 pub use rk4::rk4_integrate_inplace;
+pub use rk4::rk4_integrate_instant;
+pub use rk4::rk4_integrate_until;
+pub use rk4::rk4_integrate_with_stm;
+pub use rk45::{rk45_integrate, IntegrationStats, RKAdaptiveSettings};
+pub use rk8::rk8_integrate;
+pub use rktableau::{rk_integrate, ButcherTableau};
 
 /// Some common vector types
 pub type Vector6 = Vector<6>;
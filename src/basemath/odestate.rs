@@ -0,0 +1,232 @@
+use super::Quaternion;
+use super::Vector;
+
+/// State that can be propagated by the adaptive integrators
+///
+/// In addition to the arithmetic needed by the fixed-step methods, an
+/// adaptive integrator must measure the size of its local error estimate
+/// relative to the state. Implementors report their number of scalar
+/// elements and a scaled RMS norm so that composite states can combine
+/// the norms of their parts.
+///
+pub trait ODEState:
+    Clone + std::ops::Add<Self, Output = Self> + std::ops::Mul<f64, Output = Self>
+{
+    /// Number of scalar elements in the state
+    fn ode_nelem(&self) -> usize;
+
+    /// Scaled RMS norm of an error estimate
+    ///
+    /// # Arguments
+    /// * `y0` - State at the start of the step
+    /// * `y1` - State at the end of the step
+    /// * `atol` - Absolute tolerance
+    /// * `rtol` - Relative tolerance
+    ///
+    /// # Returns
+    /// The RMS over all elements of `e_i / (atol + rtol * max(|y0_i|, |y1_i|))`,
+    /// where `e` is `self`. A value at or below 1 meets the tolerance
+    ///
+    fn ode_norm(&self, y0: &Self, y1: &Self, atol: f64, rtol: f64) -> f64;
+
+    /// Hook applied to the state after each accepted step
+    ///
+    /// Use to project the state back onto a constraint that component-wise
+    /// integration does not preserve, e.g. the unit norm of a quaternion.
+    /// The default does nothing
+    ///
+    fn ode_post_step(&mut self) {}
+}
+
+impl ODEState for f64 {
+    fn ode_nelem(&self) -> usize {
+        1
+    }
+
+    fn ode_norm(&self, y0: &Self, y1: &Self, atol: f64, rtol: f64) -> f64 {
+        self.abs() / (atol + rtol * y0.abs().max(y1.abs()))
+    }
+}
+
+impl<const N: usize> ODEState for Vector<N> {
+    fn ode_nelem(&self) -> usize {
+        N
+    }
+
+    fn ode_norm(&self, y0: &Self, y1: &Self, atol: f64, rtol: f64) -> f64 {
+        let mut sumsq = 0.0;
+        for i in 0..N {
+            let scale = atol + rtol * y0[i].abs().max(y1[i].abs());
+            sumsq += (self[i] / scale).powi(2);
+        }
+        (sumsq / N as f64).sqrt()
+    }
+}
+
+/// Attitude quaternion as a 4-element state, renormalized after each step
+impl ODEState for Quaternion {
+    fn ode_nelem(&self) -> usize {
+        4
+    }
+
+    fn ode_norm(&self, y0: &Self, y1: &Self, atol: f64, rtol: f64) -> f64 {
+        let e = [self.x, self.y, self.z, self.w];
+        let a = [y0.x, y0.y, y0.z, y0.w];
+        let b = [y1.x, y1.y, y1.z, y1.w];
+        let sumsq = (0..4)
+            .map(|i| (e[i] / (atol + rtol * a[i].abs().max(b[i].abs()))).powi(2))
+            .sum::<f64>();
+        (sumsq / 4.0).sqrt()
+    }
+
+    fn ode_post_step(&mut self) {
+        self.normalize_inplace();
+    }
+}
+
+/// Pair of states integrated together as a single state
+///
+/// Allows heterogeneous states, e.g. an orbit `Vector6` and an attitude
+/// `Quaternion`, to be propagated by one call to an integrator. Nest
+/// composite states to combine more than two parts. The error norm is the
+/// RMS over the elements of both parts.
+///
+/// # Example
+///
+/// ```
+/// use satctrl::{rk45_integrate, CompositeState, RKAdaptiveSettings, Vector3, Vector4};
+/// type State = CompositeState<Vector3, Vector4>;
+/// let f = |_t: f64, s: &State| CompositeState::new(s.0 * -1.0, Vector4::from_vec([1.0; 4]));
+/// let y0 = CompositeState::new(Vector3::from_vec([1.0; 3]), Vector4::zeros());
+/// let (y, _) = rk45_integrate(f, 0.0, y0, 1.0, &RKAdaptiveSettings::default()).unwrap();
+/// assert!((y.1[0] - 1.0).abs() < 1.0e-9);
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CompositeState<A, B>(pub A, pub B);
+
+impl<A, B> CompositeState<A, B> {
+    /// Create a composite state from its two parts
+    pub fn new(a: A, b: B) -> Self {
+        Self(a, b)
+    }
+}
+
+impl<A, B> std::ops::Add for CompositeState<A, B>
+where
+    A: std::ops::Add<Output = A>,
+    B: std::ops::Add<Output = B>,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl<A, B> std::ops::Mul<f64> for CompositeState<A, B>
+where
+    A: std::ops::Mul<f64, Output = A>,
+    B: std::ops::Mul<f64, Output = B>,
+{
+    type Output = Self;
+
+    fn mul(self, other: f64) -> Self {
+        Self(self.0 * other, self.1 * other)
+    }
+}
+
+impl<A, B> std::ops::Div<f64> for CompositeState<A, B>
+where
+    A: std::ops::Div<f64, Output = A>,
+    B: std::ops::Div<f64, Output = B>,
+{
+    type Output = Self;
+
+    fn div(self, other: f64) -> Self {
+        Self(self.0 / other, self.1 / other)
+    }
+}
+
+impl<A: ODEState, B: ODEState> ODEState for CompositeState<A, B> {
+    fn ode_nelem(&self) -> usize {
+        self.0.ode_nelem() + self.1.ode_nelem()
+    }
+
+    fn ode_norm(&self, y0: &Self, y1: &Self, atol: f64, rtol: f64) -> f64 {
+        // Weight each part's mean square by its number of elements
+        let (na, nb) = (self.0.ode_nelem() as f64, self.1.ode_nelem() as f64);
+        let a = self.0.ode_norm(&y0.0, &y1.0, atol, rtol);
+        let b = self.1.ode_norm(&y0.1, &y1.1, atol, rtol);
+        ((na * a * a + nb * b * b) / (na + nb)).sqrt()
+    }
+
+    fn ode_post_step(&mut self) {
+        self.0.ode_post_step();
+        self.1.ode_post_step();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rk45_integrate, RKAdaptiveSettings, Vector3, Vector4};
+
+    #[test]
+    fn test_composite_state() {
+        // Norm is the RMS over all seven elements
+        let e = CompositeState::new(
+            Vector3::from_vec([1.0, 2.0, 3.0]),
+            Vector4::from_vec([4.0; 4]),
+        );
+        let zero = CompositeState::new(Vector3::zeros(), Vector4::zeros());
+        assert_eq!(e.ode_nelem(), 7);
+        let rms = ((1.0 + 4.0 + 9.0 + 4.0 * 16.0) / 7.0_f64).sqrt();
+        assert!((e.ode_norm(&zero, &zero, 1.0, 0.0) - rms).abs() < 1.0e-14);
+
+        // Decaying position coupled to a uniformly rotating 4-vector
+        type State = CompositeState<Vector3, Vector4>;
+        let f = |_t: f64, s: &State| {
+            let b = Vector4::from_vec([-s.1[1], s.1[0], -s.1[3], s.1[2]]);
+            CompositeState::new(s.0 * -1.0, b)
+        };
+        let y0 = CompositeState::new(
+            Vector3::from_vec([1.0, -2.0, 0.5]),
+            Vector4::from_vec([1.0, 0.0, 0.0, 1.0]),
+        );
+        let settings = RKAdaptiveSettings {
+            rtol: 1.0e-11,
+            atol: 1.0e-11,
+            ..Default::default()
+        };
+        let t: f64 = 2.0;
+        let (y, _) = rk45_integrate(f, 0.0, y0, t, &settings).unwrap();
+        assert!((y.0 - y0.0 * (-t).exp()).norm() < 1.0e-9);
+        let b = Vector4::from_vec([t.cos(), t.sin(), -t.sin(), t.cos()]);
+        assert!((y.1 - b).norm() < 1.0e-9);
+    }
+
+    #[test]
+    fn test_quaternion_state() {
+        // Constant-rate kinematics without the derivative's norm correction
+        let omega = Vector3::from_vec([0.3, -0.2, 0.5]);
+        let w = Quaternion::new(omega[0], omega[1], omega[2], 0.0);
+        let f = |_t: f64, q: &Quaternion| *q * w * 0.5;
+        let q0 = Quaternion::rotx(0.4);
+        let settings = RKAdaptiveSettings {
+            rtol: 1.0e-6,
+            atol: 1.0e-6,
+            ..Default::default()
+        };
+        let t = 200.0;
+        let (q, stats) = rk45_integrate(f, 0.0, q0, t, &settings).unwrap();
+        assert!(stats.accepted_steps > 10);
+        assert!((q.norm() - 1.0).abs() < 1.0e-14);
+
+        // Rotation about the body-fixed rate vector
+        let axis = omega / omega.norm();
+        let expected = q0 * Quaternion::from_axis_angle(&axis, omega.norm() * t);
+        let dot = q.x * expected.x + q.y * expected.y + q.z * expected.z + q.w * expected.w;
+        assert!(1.0 - dot.abs() < 1.0e-8);
+    }
+}
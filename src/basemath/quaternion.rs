@@ -1,10 +1,27 @@
 /// A simple quaternion implementation for vector rotations
 ///
+/// # Conventions
+/// * Hamilton quaternions, `i * j = k`, with the scalar `w` stored last
+/// * Rotations are active and right-handed: `Quaternion::rotz(θ) * v`
+///   rotates the vector `v` by +θ about +z
+/// * Products compose right to left: `(q1 * q2) * v == q1 * (q2 * v)`
+/// * `conjugate` is the inverse rotation; applying it to a vector gives
+///   the vector's coordinates in a frame rotated by the quaternion
+/// * `as_dcm` is the matching active matrix: `q.as_dcm() * v == q * v`
+/// * `q` and `-q` represent the same rotation and compare equal
+///
+/// These are pinned down by the `convention_tests` module below
+///
 /// Author: Steven Michael (ssmichael@gmail.com)
 /// Date: 2024-11-16
 ///
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "QuaternionRepr", into = "QuaternionRepr")
+)]
 pub struct Quaternion {
     pub x: f64,
     pub y: f64,
@@ -12,8 +29,39 @@ pub struct Quaternion {
     pub w: f64,
 }
 
+/// Serialized form of a quaternion, scalar first
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct QuaternionRepr {
+    w: f64,
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+#[cfg(feature = "serde")]
+impl From<QuaternionRepr> for Quaternion {
+    fn from(q: QuaternionRepr) -> Self {
+        Quaternion::new(q.x, q.y, q.z, q.w)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Quaternion> for QuaternionRepr {
+    fn from(q: Quaternion) -> Self {
+        QuaternionRepr {
+            w: q.w,
+            x: q.x,
+            y: q.y,
+            z: q.z,
+        }
+    }
+}
+
 use super::Matrix3;
 use super::Vector3;
+use super::{Matrix4, Vector4};
+use crate::{SCError, SCResult};
 
 impl Quaternion {
     /// Create a new quaternion
@@ -37,6 +85,22 @@ impl Quaternion {
         Quaternion { x, y, z, w }
     }
 
+    /// Quaternion components as an array
+    ///
+    /// # Returns
+    /// The components in (w, x, y, z) order, scalar first
+    ///
+    /// # Examples
+    /// ```
+    /// use satctrl::Quaternion;
+    /// let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(q.as_array(), [4.0, 1.0, 2.0, 3.0]);
+    /// ```
+    ///
+    pub fn as_array(&self) -> [f64; 4] {
+        [self.w, self.x, self.y, self.z]
+    }
+
     /// Create a new quaternion from a direction cosine matrix
     ///
     /// # Arguments
@@ -144,6 +208,53 @@ impl Quaternion {
         Quaternion::new(axis[0] * s, axis[1] * s, axis[2] * s, half_angle.cos())
     }
 
+    /// Shortest-arc rotation taking one vector direction to another
+    ///
+    /// # Arguments
+    /// * `from` - The starting direction, need not be unit length
+    /// * `to` - The target direction, need not be unit length
+    ///
+    /// # Returns
+    /// The quaternion `q` with the smallest rotation angle such that
+    /// `q * from` is parallel to `to`. For anti-parallel inputs the rotation
+    /// is 180° about an axis perpendicular to `from`, chosen by crossing
+    /// with the coordinate axis least aligned with `from`. Identity is
+    /// returned if either vector is zero
+    ///
+    /// # Examples
+    /// ```
+    /// use satctrl::{Quaternion, Vector3};
+    /// let q = Quaternion::from_two_vectors(&Vector3::xhat(), &Vector3::yhat());
+    /// assert_eq!(q, Quaternion::rotz(std::f64::consts::FRAC_PI_2));
+    /// ```
+    ///
+    pub fn from_two_vectors(from: &Vector3, to: &Vector3) -> Self {
+        let (nf, nt) = (from.norm(), to.norm());
+        if nf == 0.0 || nt == 0.0 {
+            return Quaternion::identity();
+        }
+        let a = *from / nf;
+        let b = *to / nt;
+        let w = 1.0 + a.dot(&b);
+        if w < 1.0e-12 {
+            // Anti-parallel: rotate by π about a stable perpendicular
+            let basis = if a[0].abs() <= a[1].abs() && a[0].abs() <= a[2].abs() {
+                Vector3::xhat()
+            } else if a[1].abs() <= a[2].abs() {
+                Vector3::yhat()
+            } else {
+                Vector3::zhat()
+            };
+            let axis = a.cross(&basis);
+            let axis = axis / axis.norm();
+            return Quaternion::new(axis[0], axis[1], axis[2], 0.0);
+        }
+        let v = a.cross(&b);
+        let mut q = Quaternion::new(v[0], v[1], v[2], w);
+        q.normalize_inplace();
+        q
+    }
+
     /// Create a new quaternion representing no rotation
     ///
     /// # Returns
@@ -180,12 +291,24 @@ impl Quaternion {
     /// Quaternion to roll, pitch, yaw
     ///
     /// # Returns
-    /// A tuple of roll, pitch, yaw in radians
+    /// A tuple of roll, pitch, yaw in radians, the inverse of `from_rpy`.
+    /// Roll and yaw are in [-π, π] and pitch is in [-π/2, π/2]
+    ///
+    /// # Examples
+    /// ```
+    /// use satctrl::Quaternion;
+    /// let (r, p, y) = Quaternion::from_rpy(0.1, -0.2, 0.3).to_rpy();
+    /// assert!((r - 0.1).abs() < 1.0e-12);
+    /// assert!((p + 0.2).abs() < 1.0e-12);
+    /// assert!((y - 0.3).abs() < 1.0e-12);
+    /// ```
+    ///
     pub fn to_rpy(&self) -> (f64, f64, f64) {
-        let r = self.x.atan2(self.w * self.z + self.x * self.y);
-        let p = -self.y.asin();
-        let y = self.x.atan2(self.w * self.y + self.z * self.x);
-        (r, p, y)
+        let (x, y, z, w) = (self.x, self.y, self.z, self.w);
+        let roll = (2.0 * (w * x + y * z)).atan2(1.0 - 2.0 * (x * x + y * y));
+        let pitch = (2.0 * (w * y - z * x)).clamp(-1.0, 1.0).asin();
+        let yaw = (2.0 * (w * z + x * y)).atan2(1.0 - 2.0 * (y * y + z * z));
+        (roll, pitch, yaw)
     }
 
     /// Create a new quaternion from roll, pitch, yaw
@@ -199,7 +322,8 @@ impl Quaternion {
     /// A new quaternion representing the rotation
     ///
     /// # Notes
-    /// * The order of rotation is roll, pitch, yaw
+    /// * The order of rotation is roll, pitch, yaw, i.e. the result is
+    ///   `rotz(yaw) * roty(pitch) * rotx(roll)`
     /// * For a reference, see:
     ///   <https://en.wikipedia.org/wiki/Conversion_between_quaternions_and_Euler_angles>
     pub fn from_rpy(roll: f64, pitch: f64, yaw: f64) -> Self {
@@ -285,6 +409,131 @@ impl Quaternion {
         )
     }
 
+    /// Spherical cubic interpolation (squad) between two quaternions
+    ///
+    /// Interpolates from `q0` to `q1` along a curve shaped by the
+    /// intermediate control quaternions `a` and `b`. With control points
+    /// from `squad_control_points`, consecutive segments join with
+    /// continuous angular velocity
+    ///
+    /// # Arguments
+    /// * `q0` - Start quaternion
+    /// * `q1` - End quaternion
+    /// * `a` - Control quaternion associated with `q0`
+    /// * `b` - Control quaternion associated with `q1`
+    /// * `t` - The interpolation distance from q0 to q1, in range [0, 1]
+    ///
+    /// # Returns
+    /// The interpolated quaternion
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use satctrl::Quaternion;
+    /// let q = [Quaternion::rotx(0.0), Quaternion::rotx(0.5), Quaternion::roty(0.5)];
+    /// let a = Quaternion::squad_control_points(&q);
+    /// let qi = Quaternion::squad(&q[0], &q[1], &a[0], &a[1], 0.5);
+    /// ```
+    ///
+    pub fn squad(
+        q0: &Quaternion,
+        q1: &Quaternion,
+        a: &Quaternion,
+        b: &Quaternion,
+        t: f64,
+    ) -> Quaternion {
+        let outer = q0.slerp(q1, t);
+        let inner = a.slerp(b, t);
+        outer.slerp(&inner, 2.0 * t * (1.0 - t))
+    }
+
+    /// Control quaternions for a squad spline through a sequence of waypoints
+    ///
+    /// For interior waypoints the control point is
+    /// `q_i * exp(-(log(q_i⁻¹ q_{i+1}) + log(q_i⁻¹ q_{i-1})) / 4)`;
+    /// the end points are their own control points
+    ///
+    /// # Arguments
+    /// * `q` - Unit-quaternion waypoints. Each should be in the same
+    ///   hemisphere as its predecessor (non-negative dot product)
+    ///
+    /// # Returns
+    /// One control quaternion per waypoint. Segment `i` uses
+    /// `squad(q[i], q[i+1], a[i], a[i+1], t)`
+    ///
+    pub fn squad_control_points(q: &[Quaternion]) -> Vec<Quaternion> {
+        let n = q.len();
+        (0..n)
+            .map(|i| {
+                if i == 0 || i == n - 1 {
+                    return q[i];
+                }
+                let qinv = q[i].conjugate();
+                // Rotation vectors are twice the quaternion logarithms
+                let rnext = (qinv * q[i + 1]).log();
+                let rprev = (qinv * q[i - 1]).log();
+                q[i] * Quaternion::exp(&((rnext + rprev) * -0.25))
+            })
+            .collect()
+    }
+
+    /// Weighted average of unit quaternions
+    ///
+    /// The average is the eigenvector of the largest eigenvalue of
+    /// `M = Σ wᵢ qᵢ qᵢᵀ`, which minimizes the weighted sum of squared
+    /// Frobenius distances between the rotation matrices. Unlike a
+    /// component-wise mean it is unaffected by the sign of each input
+    ///
+    /// # Arguments
+    /// * `quats` - The unit quaternions to average
+    /// * `weights` - Non-negative weight of each quaternion, or `None`
+    ///   for equal weights
+    ///
+    /// # Returns
+    /// The average, signed to lie in the same hemisphere as `quats[0]`.
+    /// Returns `SCError::InvalidInput` if there are no quaternions, the
+    /// weights do not match them in length, or the weights are negative or
+    /// all zero
+    ///
+    /// # Notes
+    /// * See: F. L. Markley et al., "Averaging Quaternions", J. Guidance,
+    ///   Control, and Dynamics 30(4), 2007
+    ///
+    /// # Examples
+    /// ```
+    /// use satctrl::Quaternion;
+    /// let q = [Quaternion::rotz(0.1), Quaternion::rotz(0.3)];
+    /// let avg = Quaternion::average(&q, None).unwrap();
+    /// assert!(avg.angular_distance(&Quaternion::rotz(0.2)) < 1.0e-12);
+    /// ```
+    ///
+    pub fn average(quats: &[Quaternion], weights: Option<&[f64]>) -> SCResult<Quaternion> {
+        if quats.is_empty() || weights.is_some_and(|w| w.len() != quats.len()) {
+            return Err(SCError::InvalidInput);
+        }
+        let weight = |i: usize| weights.map_or(1.0, |w| w[i]);
+        if (0..quats.len()).any(|i| !(0.0..).contains(&weight(i)))
+            || (0..quats.len()).all(|i| weight(i) == 0.0)
+        {
+            return Err(SCError::InvalidInput);
+        }
+
+        let mut m = Matrix4::zeros();
+        for (i, q) in quats.iter().enumerate() {
+            let v = Vector4::from_vec([q.x, q.y, q.z, q.w]);
+            m += v * v.transpose() * weight(i);
+        }
+        // Eigenvalues are sorted in ascending order
+        let (_, vecs) = crate::matrixutils::symmetric_eigen(&m)?;
+        let v = vecs.column(3);
+        let avg = Quaternion::new(v[0], v[1], v[2], v[3]);
+        Ok(if avg.dot(&quats[0]) < 0.0 {
+            avg * -1.0
+        } else {
+            avg
+        })
+    }
+
     /// Quaternion dot product
     ///
     /// # Arguments
@@ -408,7 +657,171 @@ impl Quaternion {
     /// ```
     ///
     pub fn angle(&self) -> f64 {
-        2.0 * self.w.acos()
+        let s = (self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+        2.0 * s.atan2(self.w)
+    }
+
+    /// Raise a unit quaternion to a real power
+    ///
+    /// The result is a rotation about the same axis by `t` times the angle
+    ///
+    /// # Arguments
+    /// * `t` - The exponent; e.g. 0.5 gives half of the rotation
+    ///
+    /// # Returns
+    /// The quaternion raised to the power `t`
+    ///
+    /// # Examples
+    /// ```
+    /// use satctrl::Quaternion;
+    /// let q = Quaternion::rotz(std::f64::consts::PI / 2.0);
+    /// let qhalf = q.pow(0.5);
+    /// assert!((qhalf.angle() - std::f64::consts::PI / 4.0).abs() < 1.0e-12);
+    /// ```
+    ///
+    pub fn pow(&self, t: f64) -> Quaternion {
+        let vnorm = (self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+        if vnorm < f64::EPSILON {
+            return Quaternion::identity();
+        }
+        let half_angle = vnorm.atan2(self.w) * t;
+        let s = half_angle.sin() / vnorm;
+        Quaternion::new(self.x * s, self.y * s, self.z * s, half_angle.cos())
+    }
+
+    /// Logarithm map: rotation vector of a unit quaternion
+    ///
+    /// The rotation vector is the rotation axis scaled by the rotation
+    /// angle, taken on the shortest path so its norm is at most π
+    ///
+    /// # Returns
+    /// The rotation vector, in radians
+    ///
+    /// # Examples
+    /// ```
+    /// use satctrl::{Quaternion, Vector3};
+    /// let q = Quaternion::rotz(0.3);
+    /// assert!((q.log() - Vector3::from_vec([0.0, 0.0, 0.3])).norm() < 1.0e-15);
+    /// ```
+    ///
+    pub fn log(&self) -> Vector3 {
+        // q and -q are the same rotation; pick the one with w >= 0
+        let q = if self.w < 0.0 { *self * -1.0 } else { *self };
+        let v = Vector3::from_vec([q.x, q.y, q.z]);
+        let s = v.norm();
+        let scale = if s < 1.0e-4 {
+            // Series of 2 atan(s / w) / s, accurate to O(s^4)
+            2.0 / q.w * (1.0 - s * s / (3.0 * q.w * q.w))
+        } else {
+            2.0 * s.atan2(q.w) / s
+        };
+        v * scale
+    }
+
+    /// Exponential map: unit quaternion from a rotation vector
+    ///
+    /// The inverse of `log`
+    ///
+    /// # Arguments
+    /// * `v` - The rotation vector (axis scaled by angle), in radians
+    ///
+    /// # Returns
+    /// The quaternion rotating by `|v|` about `v`
+    ///
+    /// # Examples
+    /// ```
+    /// use satctrl::{Quaternion, Vector3};
+    /// let q = Quaternion::exp(&Vector3::from_vec([0.0, 0.0, 0.3]));
+    /// assert_eq!(q, Quaternion::rotz(0.3));
+    /// ```
+    ///
+    pub fn exp(v: &Vector3) -> Quaternion {
+        let theta = v.norm();
+        let scale = if theta < 1.0e-4 {
+            // Series of sin(theta / 2) / theta, accurate to O(theta^4)
+            0.5 - theta * theta / 48.0
+        } else {
+            (theta / 2.0).sin() / theta
+        };
+        Quaternion::new(
+            v[0] * scale,
+            v[1] * scale,
+            v[2] * scale,
+            (theta / 2.0).cos(),
+        )
+    }
+
+    /// Modified Rodrigues parameters (MRPs) of the rotation
+    ///
+    /// σ = q_vec / (1 + q_w), with the sign of the quaternion chosen so
+    /// that q_w >= 0. This selects the set with |σ| <= 1 (rotation angle
+    /// at most 180°) and keeps the parameters away from their singularity
+    /// at a rotation of 360°
+    ///
+    /// # Returns
+    /// The MRP vector, with norm tan(angle / 4)
+    ///
+    /// # Examples
+    /// ```
+    /// use satctrl::Quaternion;
+    /// let sigma = Quaternion::rotz(1.0).to_mrp();
+    /// assert!((sigma[2] - 0.25_f64.tan()).abs() < 1.0e-15);
+    /// ```
+    ///
+    pub fn to_mrp(&self) -> Vector3 {
+        let q = if self.w < 0.0 { *self * -1.0 } else { *self };
+        Vector3::from_vec([q.x, q.y, q.z]) / (1.0 + q.w)
+    }
+
+    /// Quaternion from modified Rodrigues parameters
+    ///
+    /// Accepts either the primary or the shadow set; both give the same
+    /// rotation
+    ///
+    /// # Arguments
+    /// * `sigma` - The MRP vector
+    ///
+    /// # Returns
+    /// The unit quaternion of the rotation
+    ///
+    /// # Examples
+    /// ```
+    /// use satctrl::{Quaternion, Vector3};
+    /// let q = Quaternion::from_mrp(&Vector3::from_vec([0.0, 0.0, 0.25_f64.tan()]));
+    /// assert!(q.angular_distance(&Quaternion::rotz(1.0)) < 1.0e-15);
+    /// ```
+    ///
+    pub fn from_mrp(sigma: &Vector3) -> Quaternion {
+        let s2 = sigma.normsq();
+        let v = *sigma * (2.0 / (1.0 + s2));
+        Quaternion::new(v[0], v[1], v[2], (1.0 - s2) / (1.0 + s2))
+    }
+
+    /// Switch modified Rodrigues parameters to the shadow set if |σ| > 1
+    ///
+    /// The shadow set σˢ = -σ / |σ|² describes the same rotation. Apply
+    /// after propagating MRPs so that they stay bounded by 1
+    ///
+    /// # Arguments
+    /// * `sigma` - The MRP vector
+    ///
+    /// # Returns
+    /// The equivalent MRP vector with norm at most 1
+    ///
+    /// # Examples
+    /// ```
+    /// use satctrl::{Quaternion, Vector3};
+    /// let sigma = Quaternion::mrp_shadow(&Vector3::from_vec([0.0, 2.0, 0.0]));
+    /// assert_eq!(sigma, Vector3::from_vec([0.0, -0.5, 0.0]));
+    /// ```
+    ///
+    pub fn mrp_shadow(sigma: &Vector3) -> Vector3 {
+        let s2 = sigma.normsq();
+        if s2 > 1.0 {
+            *sigma * (-1.0 / s2)
+        } else {
+            *sigma
+        }
     }
 
     /// Quaternion derivative
@@ -479,6 +892,40 @@ impl Quaternion {
         self.normalize_inplace();
     }
 
+    /// Test whether two quaternions represent the same rotation
+    ///
+    /// `q` and `-q` represent the same rotation. Unlike `PartialEq`, which
+    /// uses a fixed tolerance and picks the sign from the scalar parts,
+    /// this compares against both signs with a caller-chosen tolerance, so
+    /// it is reliable for rotations near 180 degrees where `w` is close
+    /// to zero
+    ///
+    /// # Arguments
+    /// * `other` - The other quaternion
+    /// * `tol` - Tolerance on the difference of each component
+    ///
+    /// # Returns
+    /// True if `other` matches `self` or `-self` to within `tol`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use satctrl::Quaternion;
+    /// let q = Quaternion::rotx(0.3);
+    /// assert!(q.represents_same_rotation(&(q * -1.0), 1.0e-12));
+    /// ```
+    ///
+    pub fn represents_same_rotation(&self, other: &Quaternion, tol: f64) -> bool {
+        let a = [self.x, self.y, self.z, self.w];
+        let b = [other.x, other.y, other.z, other.w];
+        let close = |sign: f64| {
+            a.iter()
+                .zip(b.iter())
+                .all(|(a, b)| (a - sign * b).abs() <= tol)
+        };
+        close(1.0) || close(-1.0)
+    }
+
     /// Angular distance between two quaternions
     ///
     /// # Arguments
@@ -813,6 +1260,40 @@ impl std::ops::MulAssign<&Quaternion> for Quaternion {
     }
 }
 
+/// Display quaternion as `w + xi + yj + zk`
+///
+/// The formatter precision (e.g. `{:.3}`) sets the number of decimal
+/// places, defaulting to 6
+///
+/// # Example
+/// ```
+/// use satctrl::Quaternion;
+/// let q = Quaternion::new(0.5, -0.5, 0.5, 0.5);
+/// assert_eq!(format!("{:.2}", q), "0.50 + 0.50i - 0.50j + 0.50k");
+/// ```
+impl std::fmt::Display for Quaternion {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let prec = f.precision().unwrap_or(6);
+        write!(f, "{:.*}", prec, self.w)?;
+        for (v, unit) in [(self.x, 'i'), (self.y, 'j'), (self.z, 'k')] {
+            let sign = if v.is_sign_negative() { '-' } else { '+' };
+            write!(f, " {} {:.*}{}", sign, prec, v.abs(), unit)?;
+        }
+        Ok(())
+    }
+}
+
+/// Debug output with labeled components
+impl std::fmt::Debug for Quaternion {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Quaternion {{ w: {}, x: {}, y: {}, z: {} }}",
+            self.w, self.x, self.y, self.z
+        )
+    }
+}
+
 /// Test that quaternion represents identical rotations
 /// Allowing for sign ambiguities (i.e. q and -q represent the same rotation)
 impl PartialEq for Quaternion {
@@ -847,6 +1328,19 @@ mod tests {
         assert_eq!(q.w, 4.0);
     }
 
+    #[test]
+    fn test_format() {
+        let q = Quaternion::identity();
+        assert_eq!(
+            q.to_string(),
+            "1.000000 + 0.000000i + 0.000000j + 0.000000k"
+        );
+        assert_eq!(format!("{:.1}", q), "1.0 + 0.0i + 0.0j + 0.0k");
+        assert_eq!(format!("{:?}", q), "Quaternion { w: 1, x: 0, y: 0, z: 0 }");
+        let q = Quaternion::new(-0.25, 0.0, 0.5, -1.0);
+        assert_eq!(format!("{:.2}", q), "-1.00 - 0.25i + 0.00j + 0.50k");
+    }
+
     #[test]
     /// Quaternion is as expected
     fn test_id() {
@@ -936,6 +1430,200 @@ mod tests {
         assert_eq!(q, q_expected);
     }
 
+    #[test]
+    fn test_from_two_vectors() {
+        let check = |from: Vector3, to: Vector3| {
+            let q = Quaternion::from_two_vectors(&from, &to);
+            let rotated = q * from;
+            assert!((rotated / rotated.norm() - to / to.norm()).norm() < 1.0e-12);
+            q
+        };
+
+        // Orthogonal
+        let q = check(Vector3::xhat(), Vector3::yhat());
+        assert!((q.angle() - std::f64::consts::FRAC_PI_2).abs() < 1.0e-12);
+        check(
+            Vector3::from_vec([1.0, 2.0, 3.0]),
+            Vector3::from_vec([-3.0, 0.0, 1.0]),
+        );
+
+        // Parallel, with different lengths
+        let q = check(
+            Vector3::from_vec([1.0, 1.0, 0.0]),
+            Vector3::from_vec([3.0, 3.0, 0.0]),
+        );
+        assert_eq!(q, Quaternion::identity());
+
+        // Anti-parallel, including along each axis
+        for v in [
+            Vector3::xhat(),
+            Vector3::yhat(),
+            Vector3::zhat(),
+            Vector3::from_vec([0.3, -0.2, 0.9]),
+        ] {
+            let q = check(v, -1.0 * v);
+            assert!((q.angle() - std::f64::consts::PI).abs() < 1.0e-12);
+            assert!((q.norm() - 1.0).abs() < 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn test_log_exp() {
+        // Equal up to the sign ambiguity of q and -q
+        let close = |a: &Quaternion, b: &Quaternion| {
+            let b = if a.dot(b) < 0.0 { *b * -1.0 } else { *b };
+            (*a - b).norm() < 1.0e-14
+        };
+        let axis = Vector3::from_vec([0.3, -0.5, 0.8]);
+        let axis = axis / axis.norm();
+        let pi = std::f64::consts::PI;
+        for angle in [0.0, 1.0e-12, 1.0e-6, 1.0e-4, 0.3, 2.0, pi - 1.0e-9, pi, 5.0] {
+            let q = Quaternion::from_axis_angle(&axis, angle);
+            let v = q.log();
+            assert!(v.norm() <= pi + 1.0e-15);
+            assert!(close(&Quaternion::exp(&v), &q));
+            // Rotation vector is axis times angle, wrapped to the shortest path
+            if angle <= pi {
+                assert!((v - axis * angle).norm() < 1.0e-14);
+            }
+        }
+        // The sign of the quaternion does not change the rotation vector
+        let v = (Quaternion::rotz(-0.3) * -1.0).log();
+        assert!((v - Vector3::from_vec([0.0, 0.0, -0.3])).norm() < 1.0e-15);
+
+        let v = Vector3::from_vec([1.0e-7, -2.0e-7, 3.0e-8]);
+        assert!((Quaternion::exp(&v).log() - v).norm() < 1.0e-22);
+    }
+
+    #[test]
+    fn test_mrp() {
+        let axis = Vector3::from_vec([-0.2, 0.6, 0.3]);
+        let axis = axis / axis.norm();
+        for angle in [0.0, 0.1, 1.5, 3.0, std::f64::consts::PI] {
+            let q = Quaternion::from_axis_angle(&axis, angle);
+            let sigma = q.to_mrp();
+            assert!((sigma - axis * (angle / 4.0).tan()).norm() < 1.0e-14);
+            assert!(Quaternion::from_mrp(&sigma).angular_distance(&q) < 1.0e-14);
+            assert!((q * -1.0).to_mrp() == sigma);
+        }
+
+        // A 300° rotation maps to the shadow set, a -60° rotation
+        let q = Quaternion::from_axis_angle(&axis, 300.0_f64.to_radians());
+        let sigma = q.to_mrp();
+        assert!(sigma.norm() <= 1.0);
+        let primary = axis * 75.0_f64.to_radians().tan();
+        assert!(primary.norm() > 1.0);
+        let shadow = Quaternion::mrp_shadow(&primary);
+        assert!((shadow - sigma).norm() < 1.0e-14);
+        assert!((shadow + axis * 15.0_f64.to_radians().tan()).norm() < 1.0e-14);
+        // Both sets are the same rotation; the shadow set gives -q
+        assert!(Quaternion::from_mrp(&primary).angular_distance(&q) < 1.0e-14);
+        assert!((Quaternion::from_mrp(&shadow) + q).norm() < 1.0e-14);
+        assert_eq!(Quaternion::mrp_shadow(&sigma), sigma);
+    }
+
+    #[test]
+    fn test_average() {
+        // Estimates scattered about a known attitude, some sign-flipped
+        let truth = Quaternion::from_rpy(0.3, -0.2, 1.1);
+        let offsets = [
+            Vector3::from_vec([0.01, -0.02, 0.005]),
+            Vector3::from_vec([-0.01, 0.02, -0.005]),
+            Vector3::from_vec([0.015, 0.01, -0.02]),
+            Vector3::from_vec([-0.015, -0.01, 0.02]),
+        ];
+        let quats: Vec<Quaternion> = offsets
+            .iter()
+            .enumerate()
+            .map(|(i, dv)| {
+                let q = truth * Quaternion::exp(dv);
+                if i % 2 == 0 {
+                    q * -1.0
+                } else {
+                    q
+                }
+            })
+            .collect();
+        // Symmetric offsets average out up to second order
+        let avg = Quaternion::average(&quats, None).unwrap();
+        assert!((avg.conjugate() * truth).log().norm() < 1.0e-4);
+        assert!(avg.dot(&quats[0]) > 0.0);
+        assert!((avg.norm() - 1.0).abs() < 1.0e-12);
+
+        // Weights pull the average toward the heavier estimate
+        let q = [Quaternion::rotx(0.0), Quaternion::rotx(0.4)];
+        let avg = Quaternion::average(&q, Some(&[3.0, 1.0])).unwrap();
+        assert!((avg.angle() - 0.1).abs() < 0.01);
+        let avg = Quaternion::average(&q, Some(&[0.0, 1.0])).unwrap();
+        assert!(avg.angular_distance(&q[1]) < 1.0e-12);
+
+        for (quats, weights) in [
+            (&[][..], None),
+            (&q[..], Some(&[1.0][..])),
+            (&q[..], Some(&[1.0, -1.0][..])),
+            (&q[..], Some(&[0.0, 0.0][..])),
+        ] {
+            assert!(matches!(
+                Quaternion::average(quats, weights),
+                Err(SCError::InvalidInput)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_squad() {
+        // With control points at the endpoints, squad is slerp
+        let q0 = Quaternion::rotx(0.4);
+        let q1 = Quaternion::from_rpy(0.2, -0.5, 1.0);
+        for t in [0.0, 0.25, 0.5, 0.8, 1.0] {
+            let qs = Quaternion::squad(&q0, &q1, &q0, &q1, t);
+            assert!(qs.angular_distance(&q0.slerp(&q1, t)) < 1.0e-12);
+        }
+
+        // Spline through waypoints passes through them with a continuous
+        // angular velocity at the interior waypoint
+        let q = [
+            Quaternion::identity(),
+            Quaternion::rotz(0.6),
+            Quaternion::rotz(0.6) * Quaternion::rotx(0.8),
+        ];
+        let a = Quaternion::squad_control_points(&q);
+        let seg0 = |t: f64| Quaternion::squad(&q[0], &q[1], &a[0], &a[1], t);
+        let seg1 = |t: f64| Quaternion::squad(&q[1], &q[2], &a[1], &a[2], t);
+        assert!(seg0(1.0).angular_distance(&q[1]) < 1.0e-12);
+        assert!(seg1(0.0).angular_distance(&q[1]) < 1.0e-12);
+
+        let h = 1.0e-5;
+        let w0 = (q[1].conjugate() * seg0(1.0 - h)).log() * (-1.0 / h);
+        let w1 = (q[1].conjugate() * seg1(h)).log() * (1.0 / h);
+        assert!((w0 - w1).norm() < 1.0e-4);
+    }
+
+    #[test]
+    fn test_pow() {
+        let axis = Vector3::from_vec([1.0, -2.0, 0.5]);
+        let q = Quaternion::from_axis_angle(&(axis / axis.norm()), 1.3);
+        let q2 = q.pow(0.5).pow(2.0);
+        assert!(q.angular_distance(&q2) < 1.0e-12);
+
+        let q3 = q.pow(3.0);
+        assert!(q3.angular_distance(&(q * q * q)) < 1.0e-12);
+
+        assert_eq!(Quaternion::identity().pow(0.3), Quaternion::identity());
+    }
+
+    #[test]
+    fn test_mul_assign() {
+        let q1 = Quaternion::rotx(0.3);
+        let q2 = Quaternion::roty(-1.1);
+        let mut q = q1;
+        q *= q2;
+        assert_eq!(q, q1 * q2);
+        let mut q = q1;
+        q *= &q2;
+        assert_eq!(q, q1 * q2);
+    }
+
     /// Test that quatenrion rotations about axes are all right-handed
     #[test]
     fn test_righthanded_rotation() {
@@ -951,4 +1639,166 @@ mod tests {
         let xhat = q * Vector3::zhat();
         assert_eq!(xhat, Vector3::xhat());
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let q = Quaternion::new(0.5, -0.5, 0.5, 0.5);
+        let json = serde_json::to_string(&q).unwrap();
+        assert_eq!(json, r#"{"w":0.5,"x":0.5,"y":-0.5,"z":0.5}"#);
+        let q2: Quaternion = serde_json::from_str(&json).unwrap();
+        assert_eq!((q2.x, q2.y, q2.z, q2.w), (q.x, q.y, q.z, q.w));
+        let q3: Quaternion = serde_json::from_str(r#"{"x":0.0,"y":0.0,"z":0.0,"w":1.0}"#).unwrap();
+        assert_eq!(q3.w, 1.0);
+    }
+}
+
+/// Executable documentation of the rotation conventions
+#[cfg(test)]
+mod convention_tests {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    fn assert_vec_close(a: Vector3, b: Vector3) {
+        assert!((a - b).norm() < 1.0e-12, "{:?} != {:?}", a, b);
+    }
+
+    /// `rotx`, `roty`, `rotz` rotate vectors by +θ, right-handed
+    #[test]
+    fn test_axis_rotations_are_active_right_handed() {
+        let t: f64 = 0.3;
+        assert_vec_close(
+            Quaternion::rotz(t) * Vector3::xhat(),
+            Vector3::from_vec([t.cos(), t.sin(), 0.0]),
+        );
+        assert_vec_close(
+            Quaternion::rotx(t) * Vector3::yhat(),
+            Vector3::from_vec([0.0, t.cos(), t.sin()]),
+        );
+        assert_vec_close(
+            Quaternion::roty(t) * Vector3::zhat(),
+            Vector3::from_vec([t.sin(), 0.0, t.cos()]),
+        );
+        // The rotation axis itself is unchanged
+        assert_vec_close(Quaternion::rotz(t) * Vector3::zhat(), Vector3::zhat());
+        // Same as the axis-angle constructor
+        assert_eq!(
+            Quaternion::rotz(t),
+            Quaternion::from_axis_angle(&Vector3::zhat(), t)
+        );
+        assert!((Quaternion::rotz(t).angle() - t).abs() < 1.0e-12);
+        assert_vec_close(Quaternion::rotz(t).axis(), Vector3::zhat());
+    }
+
+    /// The product `q1 * q2` applies `q2` first, then `q1`
+    #[test]
+    fn test_multiplication_order() {
+        let q1 = Quaternion::rotz(FRAC_PI_2);
+        let q2 = Quaternion::rotx(FRAC_PI_2);
+        // x stays x under rotx, then rotz takes it to y
+        assert_vec_close((q1 * q2) * Vector3::xhat(), Vector3::yhat());
+        // rotz takes x to y, then rotx takes y to z
+        assert_vec_close((q2 * q1) * Vector3::xhat(), Vector3::zhat());
+
+        let v = Vector3::from_vec([0.3, -1.2, 2.0]);
+        assert_vec_close((q1 * q2) * v, q1 * (q2 * v));
+        // Hamilton convention
+        let (i, j, k) = (
+            Quaternion::new(1.0, 0.0, 0.0, 0.0),
+            Quaternion::new(0.0, 1.0, 0.0, 0.0),
+            Quaternion::new(0.0, 0.0, 1.0, 0.0),
+        );
+        let ij = i * j;
+        assert_eq!((ij.x, ij.y, ij.z, ij.w), (k.x, k.y, k.z, k.w));
+    }
+
+    /// The conjugate is the inverse rotation, or equivalently the passive
+    /// (frame) rotation
+    #[test]
+    fn test_conjugate_is_inverse() {
+        let q = Quaternion::from_rpy(0.4, -0.7, 2.1);
+        let v = Vector3::from_vec([1.0, 2.0, -0.5]);
+        assert_vec_close(q.conjugate() * (q * v), v);
+        assert_eq!(q * q.conjugate(), Quaternion::identity());
+
+        // Rotating the frame by +90° about z: the fixed vector x has
+        // coordinates (0, -1, 0) in the new frame
+        let q = Quaternion::rotz(FRAC_PI_2);
+        assert_vec_close(q.conjugate() * Vector3::xhat(), -1.0 * Vector3::yhat());
+
+        let q1 = Quaternion::rotx(0.2);
+        let q2 = Quaternion::roty(0.9);
+        assert_eq!((q1 * q2).conjugate(), q2.conjugate() * q1.conjugate());
+    }
+
+    /// `as_dcm` rotates vectors the same way, and `from_dcm` inverts it
+    #[test]
+    fn test_dcm_matches_quaternion() {
+        let q = Quaternion::from_rpy(-1.0, 0.5, 0.25);
+        let v = Vector3::from_vec([0.7, 0.1, -2.0]);
+        assert_vec_close(q.as_dcm() * v, q * v);
+        assert_eq!(Quaternion::from_dcm(&q.as_dcm()), q);
+        assert_eq!(
+            (q * Quaternion::rotz(0.3)).as_dcm(),
+            q.as_dcm() * Quaternion::rotz(0.3).as_dcm()
+        );
+    }
+
+    /// `q` and `-q` are the same rotation
+    #[test]
+    fn test_double_cover() {
+        let q = Quaternion::from_rpy(0.1, 0.2, 0.3);
+        let v = Vector3::from_vec([1.0, -1.0, 0.5]);
+        let neg = q * -1.0;
+        assert_eq!(q, neg);
+        assert_vec_close(q * v, neg * v);
+        // A full turn gives -identity, which is still the identity rotation
+        assert_eq!(Quaternion::rotz(2.0 * PI), Quaternion::identity());
+
+        assert!(q.represents_same_rotation(&neg, 1.0e-15));
+        assert!(neg.represents_same_rotation(&q, 1.0e-15));
+        assert!(!q.represents_same_rotation(&Quaternion::from_rpy(0.1, 0.2, 0.31), 1.0e-6));
+        // Half turns, where the scalar parts differ only by rounding
+        let a = Quaternion::new(0.6, 0.8, 0.0, 1.0e-17);
+        let b = Quaternion::new(-0.6, -0.8, 0.0, 1.0e-17);
+        assert!(a.represents_same_rotation(&b, 1.0e-12));
+        let c = Quaternion::rotx(PI);
+        assert!(c.represents_same_rotation(&Quaternion::rotx(-PI), 1.0e-12));
+    }
+
+    /// Roll, pitch, yaw are applied about x, then y, then z
+    #[test]
+    fn test_rpy_order() {
+        let (r, p, y) = (0.3, -0.4, 1.1);
+        let q = Quaternion::from_rpy(r, p, y);
+        assert_eq!(
+            q,
+            Quaternion::rotz(y) * Quaternion::roty(p) * Quaternion::rotx(r)
+        );
+        let (r2, p2, y2) = q.to_rpy();
+        assert!((r2 - r).abs() < 1.0e-12);
+        assert!((p2 - p).abs() < 1.0e-12);
+        assert!((y2 - y).abs() < 1.0e-12);
+
+        // Angles near the limits of their ranges
+        for (r, p, y) in [(3.0, 1.5, -3.0), (-2.5, -1.5, 0.1), (0.0, 0.0, PI - 1.0e-6)] {
+            let (r2, p2, y2) = Quaternion::from_rpy(r, p, y).to_rpy();
+            assert!((r2 - r).abs() < 1.0e-9);
+            assert!((p2 - p).abs() < 1.0e-9);
+            assert!((y2 - y).abs() < 1.0e-9);
+        }
+    }
+
+    /// `derivative` takes the angular rate in the rotated (body) frame,
+    /// q̇ = ½ q ⊗ ω, so integrating a constant rate right-multiplies
+    #[test]
+    fn test_derivative_body_rate() {
+        let q0 = Quaternion::rotx(0.5);
+        let omega = Vector3::from_vec([0.0, 0.0, 0.2]);
+        let mut q = q0;
+        for _ in 0..100 {
+            q.integrate_inplace_rk4(&omega, 0.05);
+        }
+        assert!(q.angular_distance(&(q0 * Quaternion::rotz(1.0))) < 1.0e-10);
+    }
 }
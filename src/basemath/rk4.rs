@@ -1,3 +1,5 @@
+use crate::{Duration, Instant, Matrix, SCError, SCResult, Vector};
+
 /// Runga-Kutta 4th order method
 ///
 /// Integrate a function using the Runge-Kutta 4th order method.
@@ -77,3 +79,383 @@ where
     let k4 = f(x + h, &(y.clone() + k3.clone())) * h;
     *y = y.clone() + (k1 + k2 * 2.0 + k3 * 2.0 + k4) / 6.0;
 }
+
+/// Runge-Kutta 4th order integration of a state and its state-transition matrix
+///
+/// Integrates the state `y` together with the variational equations
+/// `Φ̇ = F(t, y) · Φ`, where `F = ∂f/∂y` is the Jacobian of the dynamics.
+/// Steps of size `dt` are taken from `t0`, with the final step shortened
+/// to land exactly on `t1`.
+///
+/// # Arguments
+/// * `f` - The dynamics dy/dt = f(t, y)
+/// * `dfdx` - The Jacobian of the dynamics with respect to the state
+/// * `y0` - The state at `t0`
+/// * `phi0` - The state-transition matrix at `t0`, usually identity
+/// * `t0` - The start time
+/// * `t1` - The end time, not before `t0`
+/// * `dt` - The (positive) step size
+///
+/// # Returns
+/// The state and state-transition matrix at `t1`, or
+/// `SCError::InvalidInput` if `dt` is not finite and positive
+///
+/// # Example
+///
+/// ```
+/// use satctrl::rk4_integrate_with_stm;
+/// use satctrl::{Matrix2, Vector2};
+/// let a = Matrix2::from_row_major_array([[0.0, 1.0], [-1.0, 0.0]]);
+/// let f = |_t: f64, y: &Vector2| a * *y;
+/// let dfdx = |_t: f64, _y: &Vector2| a;
+/// let (y, phi) = rk4_integrate_with_stm(
+///     f, dfdx, &Vector2::from_vec([1.0, 0.0]), &Matrix2::identity(), 0.0, 1.0, 0.01,
+/// )
+/// .unwrap();
+/// ```
+///
+pub fn rk4_integrate_with_stm<const N: usize>(
+    f: impl Fn(f64, &Vector<N>) -> Vector<N>,
+    dfdx: impl Fn(f64, &Vector<N>) -> Matrix<N, N>,
+    y0: &Vector<N>,
+    phi0: &Matrix<N, N>,
+    t0: f64,
+    t1: f64,
+    dt: f64,
+) -> SCResult<(Vector<N>, Matrix<N, N>)> {
+    if !(dt.is_finite() && dt > 0.0) {
+        return Err(SCError::InvalidInput);
+    }
+    let mut y = *y0;
+    let mut phi = *phi0;
+    let mut t = t0;
+    while t < t1 {
+        let h = dt.min(t1 - t);
+        let ky1 = f(t, &y) * h;
+        let kp1 = dfdx(t, &y) * phi * h;
+        let ya = y + ky1 / 2.0;
+        let ky2 = f(t + h / 2.0, &ya) * h;
+        let kp2 = dfdx(t + h / 2.0, &ya) * (phi + kp1 / 2.0) * h;
+        let yb = y + ky2 / 2.0;
+        let ky3 = f(t + h / 2.0, &yb) * h;
+        let kp3 = dfdx(t + h / 2.0, &yb) * (phi + kp2 / 2.0) * h;
+        let yc = y + ky3;
+        let ky4 = f(t + h, &yc) * h;
+        let kp4 = dfdx(t + h, &yc) * (phi + kp3) * h;
+        y += (ky1 + ky2 * 2.0 + ky3 * 2.0 + ky4) / 6.0;
+        phi += (kp1 + kp2 * 2.0 + kp3 * 2.0 + kp4) / 6.0;
+        t += h;
+    }
+    Ok((y, phi))
+}
+
+/// Runge-Kutta 4th order integration sampled at the given times
+///
+/// Each interval between consecutive times is divided into equal steps
+/// no longer than `dt`, so the state is returned exactly at every
+/// requested time without interpolation.
+///
+/// # Arguments
+/// * `f` - The function to integrate (dy/dt)
+/// * `y0` - The state at `times[0]`
+/// * `times` - The sample times; need not be uniformly spaced, and
+///   consecutive times may decrease to integrate backwards
+/// * `dt` - The largest (positive) step size
+///
+/// # Returns
+/// The state at each of `times`, starting with `y0`, or
+/// `SCError::InvalidInput` if `dt` is not finite and positive
+///
+/// # Example
+///
+/// ```
+/// use satctrl::rk4_integrate_at;
+/// let f = |_t: f64, y: &f64| *y;
+/// let ys = rk4_integrate_at(f, 1.0, &[0.0, 0.3, 1.0], 0.01).unwrap();
+/// assert!((ys[2] - 1.0_f64.exp()).abs() < 1.0e-9);
+/// ```
+///
+pub fn rk4_integrate_at<F, S>(f: F, y0: S, times: &[f64], dt: f64) -> SCResult<Vec<S>>
+where
+    F: Fn(f64, &S) -> S,
+    S: std::ops::Div<f64, Output = S>
+        + std::ops::Mul<f64, Output = S>
+        + std::ops::Add<S, Output = S>
+        + Clone,
+{
+    if !(dt.is_finite() && dt > 0.0) {
+        return Err(SCError::InvalidInput);
+    }
+    let mut out = Vec::with_capacity(times.len());
+    if times.is_empty() {
+        return Ok(out);
+    }
+    let mut y = y0;
+    out.push(y.clone());
+    for pair in times.windows(2) {
+        let (t0, t1) = (pair[0], pair[1]);
+        let nsteps = ((t1 - t0).abs() / dt).ceil() as usize;
+        let h = (t1 - t0) / nsteps.max(1) as f64;
+        for i in 0..nsteps {
+            y = rk4_integrate(&f, t0 + i as f64 * h, y, h);
+        }
+        out.push(y.clone());
+    }
+    Ok(out)
+}
+
+/// Runge-Kutta 4th order integration with `Instant` as the time variable
+///
+/// Steps of `dt` are taken from `t0` to `t1`, with the last step
+/// shortened to end exactly on `t1`. Internally the time is carried as
+/// seconds since `t0`, so long spans far from J2000 lose no precision.
+///
+/// # Arguments
+/// * `f` - The function to integrate (dy/dt, per second)
+/// * `y0` - The state at `t0`
+/// * `t0` - The start time
+/// * `t1` - The final time; may be earlier than `t0`
+/// * `dt` - The (positive) step size
+///
+/// # Returns
+/// The state at `t1`, or `SCError::InvalidInput` if `dt` is not positive
+///
+/// # Notes
+/// * The instants passed to `f` at the intermediate stages are rounded
+///   to the microsecond resolution of `Instant`
+///
+/// # Example
+///
+/// ```
+/// use satctrl::{rk4_integrate_instant, Duration, Instant};
+/// let t0 = Instant::from_gregorian(2024, 1, 1, 0, 0, 0.0).unwrap();
+/// let t1 = t0 + Duration::from_minutes(10.0);
+/// let f = |_tm: &Instant, y: &f64| -0.001 * y;
+/// let y = rk4_integrate_instant(f, 1.0, t0, t1, Duration::from_seconds(10.0)).unwrap();
+/// assert!((y - (-0.6_f64).exp()).abs() < 1.0e-9);
+/// ```
+///
+pub fn rk4_integrate_instant<F, S>(
+    f: F,
+    y0: S,
+    t0: Instant,
+    t1: Instant,
+    dt: Duration,
+) -> SCResult<S>
+where
+    F: Fn(&Instant, &S) -> S,
+    S: std::ops::Div<f64, Output = S>
+        + std::ops::Mul<f64, Output = S>
+        + std::ops::Add<S, Output = S>
+        + Clone,
+{
+    if dt.usec <= 0 {
+        return Err(SCError::InvalidInput);
+    }
+    let g = |s: f64, y: &S| f(&(t0 + Duration::from_seconds(s)), y);
+    let total = t1.raw - t0.raw;
+    let dir = total.signum();
+    let mut elapsed = 0;
+    let mut y = y0;
+    while elapsed != total {
+        let h = dir * dt.usec.min((total - elapsed).abs());
+        y = rk4_integrate(g, elapsed as f64 * 1.0e-6, y, h as f64 * 1.0e-6);
+        elapsed += h;
+    }
+    Ok(y)
+}
+
+/// Runge-Kutta 4th order integration until a stop condition is met
+///
+/// Steps of `dt` are taken from `t0` toward `t_max`. When `stop` first
+/// returns true at the end of a step, the step is bisected to locate the
+/// earliest time at which the condition holds.
+///
+/// # Arguments
+/// * `f` - The function to integrate (dy/dt)
+/// * `y0` - The state at `t0`
+/// * `t0` - The start time
+/// * `t_max` - The time at which to give up; may be less than `t0`
+/// * `dt` - The (positive) step size
+/// * `stop` - The terminal condition, evaluated after each step
+///
+/// # Returns
+/// The time and state at which `stop` first holds, to a relative time
+/// resolution of about 1e-12 of the step, or the time and state at `t_max`
+/// if it never does. Returns `SCError::InvalidInput` if `dt` is not finite
+/// and positive
+///
+/// # Example
+///
+/// ```
+/// use satctrl::{rk4_integrate_until, Vector2};
+/// // Falling object: position and velocity
+/// let f = |_t: f64, y: &Vector2| Vector2::from_vec([y[1], -9.81]);
+/// let y0 = Vector2::from_vec([100.0, 0.0]);
+/// let (t, y) = rk4_integrate_until(f, y0, 0.0, 60.0, 0.1, |_t, y: &Vector2| y[0] <= 0.0).unwrap();
+/// assert!((t - (200.0_f64 / 9.81).sqrt()).abs() < 1.0e-9);
+/// ```
+///
+pub fn rk4_integrate_until<F, S>(
+    f: F,
+    y0: S,
+    t0: f64,
+    t_max: f64,
+    dt: f64,
+    stop: impl Fn(f64, &S) -> bool,
+) -> SCResult<(f64, S)>
+where
+    F: Fn(f64, &S) -> S,
+    S: std::ops::Div<f64, Output = S>
+        + std::ops::Mul<f64, Output = S>
+        + std::ops::Add<S, Output = S>
+        + Clone,
+{
+    if !(dt.is_finite() && dt > 0.0) {
+        return Err(SCError::InvalidInput);
+    }
+    if stop(t0, &y0) {
+        return Ok((t0, y0));
+    }
+    let dir = (t_max - t0).signum();
+    let mut t = t0;
+    let mut y = y0;
+    while (t_max - t) * dir > 0.0 {
+        let last = (t_max - t).abs() <= dt;
+        let h = if last { t_max - t } else { dir * dt };
+        let ynext = rk4_integrate(&f, t, y.clone(), h);
+        let tnext = if last { t_max } else { t + h };
+        if stop(tnext, &ynext) {
+            // Bisect, always stepping from the last state before the stop
+            let (mut lo, mut hi) = (0.0, h);
+            let mut yhi = ynext;
+            while (hi - lo).abs() > 1.0e-12 * h.abs() {
+                let mid = 0.5 * (lo + hi);
+                let ymid = rk4_integrate(&f, t, y.clone(), mid);
+                if stop(t + mid, &ymid) {
+                    hi = mid;
+                    yhi = ymid;
+                } else {
+                    lo = mid;
+                }
+            }
+            return Ok((t + hi, yhi));
+        }
+        t = tnext;
+        y = ynext;
+    }
+    Ok((t, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Matrix2;
+
+    #[test]
+    fn test_stm_linear() {
+        // Eigenvalues -1 and -2; the STM is exp(F t) in closed form
+        let a = Matrix2::from_row_major_array([[0.0, 1.0], [-2.0, -3.0]]);
+        let f = |_t: f64, y: &Vector<2>| a * *y;
+        let dfdx = |_t: f64, _y: &Vector<2>| a;
+        let y0 = Vector::<2>::from_vec([1.0, -0.5]);
+        let t = 1.25;
+        let (y, phi) =
+            rk4_integrate_with_stm(f, dfdx, &y0, &Matrix2::identity(), 0.0, t, 0.01).unwrap();
+
+        let (e1, e2) = ((-t).exp(), (-2.0 * t).exp());
+        let expected = Matrix2::from_row_major_array([
+            [2.0 * e1 - e2, e1 - e2],
+            [-2.0 * e1 + 2.0 * e2, -e1 + 2.0 * e2],
+        ]);
+        let yexp = expected * y0;
+        for i in 0..2 {
+            assert!((y[i] - yexp[i]).abs() < 1.0e-9);
+            for j in 0..2 {
+                assert!((phi[(i, j)] - expected[(i, j)]).abs() < 1.0e-9);
+            }
+        }
+
+        for dt in [0.0, -1.0] {
+            assert!(matches!(
+                rk4_integrate_with_stm(f, dfdx, &y0, &Matrix2::identity(), 0.0, t, dt),
+                Err(SCError::InvalidInput)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_rk4_integrate_at() {
+        let times = [0.0, 0.013, 0.5, 0.51, 1.7, 1.7, 3.0, 2.2];
+        let ys = rk4_integrate_at(|_t, y: &f64| *y, 1.0, &times, 0.02).unwrap();
+        assert_eq!(ys.len(), times.len());
+        assert_eq!(ys[0], 1.0);
+        for (t, y) in times.iter().zip(ys.iter()) {
+            assert!((y / t.exp() - 1.0).abs() < 1.0e-7);
+        }
+        assert!(rk4_integrate_at(|_t, y: &f64| *y, 1.0, &[], 0.05)
+            .unwrap()
+            .is_empty());
+        assert!(matches!(
+            rk4_integrate_at(|_t, y: &f64| *y, 1.0, &times, 0.0),
+            Err(SCError::InvalidInput)
+        ));
+    }
+
+    #[test]
+    fn test_rk4_integrate_until() {
+        // Falling object; RK4 is exact for the quadratic trajectory
+        let g = 9.81;
+        let f = |_t: f64, y: &Vector<2>| Vector::<2>::from_vec([y[1], -g]);
+        let y0 = Vector::<2>::from_vec([100.0, 5.0]);
+        let ground = |_t: f64, y: &Vector<2>| y[0] <= 0.0;
+        let (t, y) = rk4_integrate_until(f, y0, 2.0, 100.0, 0.25, ground).unwrap();
+        let tfall = (5.0 + (25.0 + 2.0 * g * 100.0_f64).sqrt()) / g;
+        assert!((t - 2.0 - tfall).abs() < 1.0e-10);
+        assert!(y[0] <= 0.0 && y[0] > -1.0e-8);
+        assert!((y[1] - (5.0 - g * tfall)).abs() < 1.0e-9);
+
+        // Gives up at the maximum time
+        let (t, y) = rk4_integrate_until(f, y0, 0.0, 1.05, 0.25, ground).unwrap();
+        assert_eq!(t, 1.05);
+        assert!((y[0] - (100.0 + 5.0 * 1.05 - 0.5 * g * 1.05 * 1.05)).abs() < 1.0e-10);
+
+        // Already stopped
+        let (t, _) = rk4_integrate_until(f, y0, 0.0, 10.0, 0.25, |_t, _y| true).unwrap();
+        assert_eq!(t, 0.0);
+    }
+
+    #[test]
+    fn test_rk4_integrate_instant() {
+        // Forced oscillator driven by the time of day, matched against the
+        // same equation with time in seconds since the start
+        let t0 = Instant::from_gregorian(2024, 3, 1, 6, 0, 0.0).unwrap();
+        let w = 2.0 * std::f64::consts::PI / 86400.0;
+        let fs =
+            |s: f64, y: &Vector<2>| Vector::<2>::from_vec([y[1], -w * w * y[0] + (w * s).cos()]);
+        let fi = |tm: &Instant, y: &Vector<2>| fs((*tm - t0).as_seconds(), y);
+        let y0 = Vector::<2>::from_vec([1.0, 0.0]);
+
+        let t1 = t0 + Duration::from_hours(7.5);
+        let y = rk4_integrate_instant(fi, y0, t0, t1, Duration::from_seconds(60.0)).unwrap();
+        let expected = rk4_integrate_at(fs, y0, &[0.0, 7.5 * 3600.0], 60.0).unwrap();
+        assert!((y - expected[1]).norm() < 1.0e-9);
+
+        // A step that does not divide the span, and back again
+        let t1 = t0 + Duration::from_seconds(1000.5);
+        let y = rk4_integrate_instant(fi, y0, t0, t1, Duration::from_seconds(7.0)).unwrap();
+        let expected = rk4_integrate_at(fs, y0, &[0.0, 1000.5], 7.0).unwrap();
+        assert!((y - expected[1]).norm() < 1.0e-9);
+        let back = rk4_integrate_instant(fi, y, t1, t0, Duration::from_seconds(7.0)).unwrap();
+        assert!((back - y0).norm() < 1.0e-9);
+
+        assert_eq!(
+            rk4_integrate_instant(fi, y0, t0, t0, Duration::from_seconds(1.0)).unwrap(),
+            y0
+        );
+        assert!(matches!(
+            rk4_integrate_instant(fi, y0, t0, t1, Duration::from_seconds(0.0)),
+            Err(SCError::InvalidInput)
+        ));
+    }
+}
@@ -0,0 +1,348 @@
+use super::ODEState;
+use crate::{SCError, SCResult};
+
+/// Settings for the adaptive Runge-Kutta integrator
+///
+#[derive(Clone, Debug)]
+pub struct RKAdaptiveSettings {
+    /// Relative tolerance on the local error
+    pub rtol: f64,
+    /// Absolute tolerance on the local error
+    pub atol: f64,
+    /// Largest allowed step size (magnitude)
+    pub max_step: f64,
+    /// Smallest allowed step size (magnitude); failing to meet the
+    /// tolerance at this step size is an error
+    pub min_step: f64,
+}
+
+impl Default for RKAdaptiveSettings {
+    fn default() -> Self {
+        Self {
+            rtol: 1.0e-8,
+            atol: 1.0e-8,
+            max_step: f64::INFINITY,
+            min_step: 0.0,
+        }
+    }
+}
+
+/// Step statistics reported by the adaptive integrator
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IntegrationStats {
+    /// Number of steps that met the tolerance
+    pub accepted_steps: usize,
+    /// Number of steps rejected and retried with a smaller step size
+    pub rejected_steps: usize,
+    /// Number of evaluations of the derivative function
+    pub function_evals: usize,
+    /// Smallest accepted step size (magnitude), zero if no steps were taken
+    pub min_step: f64,
+    /// Largest accepted step size (magnitude)
+    pub max_step: f64,
+}
+
+// Dormand-Prince 5(4) coefficients
+const C: [f64; 7] = [0.0, 1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+const A2: [f64; 1] = [1.0 / 5.0];
+const A3: [f64; 2] = [3.0 / 40.0, 9.0 / 40.0];
+const A4: [f64; 3] = [44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0];
+const A5: [f64; 4] = [
+    19372.0 / 6561.0,
+    -25360.0 / 2187.0,
+    64448.0 / 6561.0,
+    -212.0 / 729.0,
+];
+const A6: [f64; 5] = [
+    9017.0 / 3168.0,
+    -355.0 / 33.0,
+    46732.0 / 5247.0,
+    49.0 / 176.0,
+    -5103.0 / 18656.0,
+];
+/// 5th-order weights; also the last row of A (first same as last)
+const B: [f64; 6] = [
+    35.0 / 384.0,
+    0.0,
+    500.0 / 1113.0,
+    125.0 / 192.0,
+    -2187.0 / 6784.0,
+    11.0 / 84.0,
+];
+/// Difference between the 5th- and embedded 4th-order weights
+const E: [f64; 7] = [
+    71.0 / 57600.0,
+    0.0,
+    -71.0 / 16695.0,
+    71.0 / 1920.0,
+    -17253.0 / 339200.0,
+    22.0 / 525.0,
+    -1.0 / 40.0,
+];
+
+/// y + h * Σ a_i k_i
+fn combine<S: ODEState>(y: &S, h: f64, a: &[f64], k: &[S]) -> S {
+    a.iter()
+        .zip(k)
+        .fold(y.clone(), |acc, (ai, ki)| acc + ki.clone() * (h * ai))
+}
+
+/// Adaptive Runge-Kutta method (Dormand-Prince 5(4))
+///
+/// Integrate from `x0` to `x1` with a step size chosen to keep the local
+/// error estimate within tolerance. The 5th-order solution is propagated,
+/// with the embedded 4th-order solution used for error control.
+///
+/// # Arguments
+/// * `f` - The function to integrate (dy/dx)
+/// * `x0` - The starting x value
+/// * `y0` - The starting y (state) value
+/// * `x1` - The final x value; may be less than `x0`
+/// * `settings` - Error tolerances and step size limits
+///
+/// # Returns
+///
+/// The y value at `x1` and the step statistics, or
+/// `SCError::StepSizeTooSmall` if a step at `settings.min_step` (or at the
+/// resolution of `x`) fails to meet the tolerance. Only the final step,
+/// which ends exactly at `x1`, may be shorter than `settings.min_step`.
+/// Returns `SCError::InvalidInput` if `min_step` exceeds `max_step`.
+/// A step whose error estimate is not finite (e.g. `f` returned NaN) is
+/// rejected, so a non-finite derivative also ends in `StepSizeTooSmall`
+///
+/// # Notes
+/// * `ODEState::ode_post_step` is applied to the state after each
+///   accepted step, e.g. to renormalize a quaternion
+/// * See: J. R. Dormand and P. J. Prince, "A family of embedded
+///   Runge-Kutta formulae", J. Comp. Appl. Math. 6 (1980)
+/// * The initial step size follows Hairer, Nørsett, and Wanner, "Solving
+///   Ordinary Differential Equations I", Sec. II.4
+///
+/// # Example
+///
+/// ```
+/// use satctrl::{rk45_integrate, RKAdaptiveSettings, Vector2};
+/// // 1D harmonic oscillator. 1st state is position, 2nd is velocity.
+/// let f = |_x: f64, y: &Vector2| Vector2::from_vec([y[1], -y[0]]);
+/// let y0 = Vector2::from_vec([1.0, 0.0]);
+/// let (y, stats) = rk45_integrate(f, 0.0, y0, 1.0, &RKAdaptiveSettings::default()).unwrap();
+/// assert!((y[0] - 1.0_f64.cos()).abs() < 1.0e-7);
+/// ```
+///
+pub fn rk45_integrate<F, S>(
+    f: F,
+    x0: f64,
+    y0: S,
+    x1: f64,
+    settings: &RKAdaptiveSettings,
+) -> SCResult<(S, IntegrationStats)>
+where
+    F: Fn(f64, &S) -> S,
+    S: ODEState,
+{
+    if !(0.0..=settings.max_step).contains(&settings.min_step) || settings.max_step <= 0.0 {
+        return Err(SCError::InvalidInput);
+    }
+    let (rtol, atol) = (settings.rtol, settings.atol);
+    let mut stats = IntegrationStats::default();
+    if x1 == x0 {
+        return Ok((y0, stats));
+    }
+    let dir = (x1 - x0).signum();
+
+    // Initial step size
+    let mut k1 = f(x0, &y0);
+    let d0 = y0.ode_norm(&y0, &y0, atol, rtol);
+    let d1 = k1.ode_norm(&y0, &y0, atol, rtol);
+    let h0 = if d0 < 1.0e-5 || d1 < 1.0e-5 {
+        1.0e-6
+    } else {
+        0.01 * d0 / d1
+    };
+    let k = f(x0 + dir * h0, &(y0.clone() + k1.clone() * (dir * h0)));
+    let d2 = (k + k1.clone() * -1.0).ode_norm(&y0, &y0, atol, rtol) / h0;
+    let h1 = if d1.max(d2) <= 1.0e-15 {
+        (h0 * 1.0e-3).max(1.0e-6)
+    } else {
+        (0.01 / d1.max(d2)).powf(0.2)
+    };
+    let mut h = (100.0 * h0)
+        .min(h1)
+        .clamp(settings.min_step, settings.max_step)
+        .min((x1 - x0).abs());
+    stats.function_evals = 2;
+    stats.min_step = f64::INFINITY;
+
+    let mut x = x0;
+    let mut y = y0;
+    loop {
+        let remaining = (x1 - x).abs();
+        let last = h >= remaining;
+        if last {
+            h = remaining;
+        }
+        let hs = dir * h;
+
+        let mut k = Vec::with_capacity(7);
+        k.push(k1.clone());
+        for (c, a) in C[1..6]
+            .iter()
+            .zip([&A2[..], &A3[..], &A4[..], &A5[..], &A6[..]])
+        {
+            let ki = f(x + c * hs, &combine(&y, hs, a, &k));
+            k.push(ki);
+        }
+        let ynew = combine(&y, hs, &B, &k);
+        k.push(f(x + hs, &ynew));
+        stats.function_evals += 6;
+
+        let err = combine(&(k[0].clone() * 0.0), hs, &E, &k).ode_norm(&y, &ynew, atol, rtol);
+        // A NaN or infinite derivative fails the step and shrinks it by the
+        // largest allowed factor, so a persistent one ends in StepSizeTooSmall
+        let err = if err.is_finite() { err } else { f64::INFINITY };
+        let factor = if err == 0.0 {
+            5.0
+        } else {
+            (0.9 * err.powf(-0.2)).clamp(0.2, 5.0)
+        };
+
+        if err <= 1.0 {
+            stats.accepted_steps += 1;
+            stats.min_step = stats.min_step.min(h);
+            stats.max_step = stats.max_step.max(h);
+            x = if last { x1 } else { x + hs };
+            y = ynew;
+            y.ode_post_step();
+            if last {
+                return Ok((y, stats));
+            }
+            k1 = k.pop().unwrap();
+            h = (h * factor).clamp(settings.min_step, settings.max_step);
+        } else {
+            stats.rejected_steps += 1;
+            if h <= settings.min_step || h <= 4.0 * f64::EPSILON * x.abs().max(1.0) {
+                return Err(SCError::StepSizeTooSmall);
+            }
+            h = (h * factor.min(1.0)).max(settings.min_step);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Vector3, Vector6};
+    use std::cell::Cell;
+
+    #[test]
+    fn test_rk45_exponential() {
+        // y' = -y, forward and backward
+        let settings = RKAdaptiveSettings {
+            rtol: 1.0e-10,
+            atol: 1.0e-12,
+            ..Default::default()
+        };
+        let (y, stats) = rk45_integrate(|_x, y: &f64| -y, 0.0, 1.0, 5.0, &settings).unwrap();
+        assert!((y - (-5.0_f64).exp()).abs() < 1.0e-9);
+        assert!(stats.accepted_steps > 0);
+        let (y, _) = rk45_integrate(|_x, y: &f64| -y, 5.0, y, 0.0, &settings).unwrap();
+        assert!((y - 1.0).abs() < 1.0e-8);
+    }
+
+    #[test]
+    fn test_rk45_stats() {
+        // Eccentric Kepler orbit, with the step size shrinking near perigee
+        let mu = 1.0;
+        let evals = Cell::new(0);
+        let f = |_x: f64, s: &Vector6| {
+            evals.set(evals.get() + 1);
+            let r = Vector3::from_vec([s[0], s[1], s[2]]);
+            let a = r * (-mu / r.norm().powi(3));
+            Vector6::from_vec([s[3], s[4], s[5], a[0], a[1], a[2]])
+        };
+        let e: f64 = 0.9;
+        let rp = 1.0 - e;
+        let vp = (mu * (1.0 + e) / rp).sqrt();
+        let y0 = Vector6::from_vec([rp, 0.0, 0.0, 0.0, vp, 0.0]);
+        let period = 2.0 * std::f64::consts::PI;
+        let settings = RKAdaptiveSettings {
+            rtol: 1.0e-9,
+            atol: 1.0e-9,
+            ..Default::default()
+        };
+        let (y, stats) = rk45_integrate(f, 0.0, y0, period, &settings).unwrap();
+
+        // Back at perigee after one orbit
+        assert!((y - y0).norm() < 1.0e-4);
+
+        assert!(stats.accepted_steps > 0);
+        assert!(stats.rejected_steps > 0);
+        assert_eq!(stats.function_evals, evals.get());
+        // Two evaluations to pick the initial step, then six per attempt
+        let attempts = (evals.get() - 2) / 6;
+        assert_eq!(stats.accepted_steps + stats.rejected_steps, attempts);
+        assert!(stats.min_step > 0.0);
+        assert!(stats.max_step > 10.0 * stats.min_step);
+        assert!(stats.max_step <= period);
+    }
+
+    #[test]
+    fn test_rk45_step_limits() {
+        // Eccentric orbit as above
+        let f = |_x: f64, s: &Vector6| {
+            let r = Vector3::from_vec([s[0], s[1], s[2]]);
+            let a = r * (-1.0 / r.norm().powi(3));
+            Vector6::from_vec([s[3], s[4], s[5], a[0], a[1], a[2]])
+        };
+        let y0 = Vector6::from_vec([0.1, 0.0, 0.0, 0.0, 19.0_f64.sqrt(), 0.0]);
+        let period = 2.0 * std::f64::consts::PI;
+
+        let settings = RKAdaptiveSettings {
+            max_step: 0.05,
+            ..Default::default()
+        };
+        let (_, stats) = rk45_integrate(f, 0.0, y0, period, &settings).unwrap();
+        assert!(stats.max_step <= 0.05);
+        assert!(stats.accepted_steps >= (period / 0.05).ceil() as usize);
+
+        // Perigee passage needs steps far below 0.01
+        let settings = RKAdaptiveSettings {
+            min_step: 0.01,
+            ..Default::default()
+        };
+        assert!(matches!(
+            rk45_integrate(f, 0.0, y0, period, &settings),
+            Err(SCError::StepSizeTooSmall)
+        ));
+
+        let settings = RKAdaptiveSettings {
+            min_step: 1.0,
+            max_step: 0.5,
+            ..Default::default()
+        };
+        assert!(matches!(
+            rk45_integrate(f, 0.0, y0, period, &settings),
+            Err(SCError::InvalidInput)
+        ));
+    }
+
+    #[test]
+    fn test_rk45_nan_derivative() {
+        // The derivative turns NaN partway through; this must fail, not hang
+        let f = |x: f64, y: &f64| if x > 0.5 { f64::NAN } else { -y };
+        assert!(matches!(
+            rk45_integrate(f, 0.0, 1.0, 2.0, &Default::default()),
+            Err(SCError::StepSizeTooSmall)
+        ));
+
+        let settings = RKAdaptiveSettings {
+            min_step: 1.0e-3,
+            ..Default::default()
+        };
+        assert!(matches!(
+            rk45_integrate(f, 0.0, 1.0, 2.0, &settings),
+            Err(SCError::StepSizeTooSmall)
+        ));
+    }
+}
@@ -0,0 +1,184 @@
+/// Runge-Kutta 8th order method
+///
+/// Integrate a function using the fixed-step 11-stage, 8th-order method
+/// of Cooper and Verner. Each step costs 11 function evaluations (vs. 4
+/// for RK4), but the local error falls as h^9, so for smooth dynamics far
+/// larger steps can be taken for the same accuracy.
+///
+/// # Arguments
+/// * `f` - The function to integrate (dy/dx)
+/// * `x` - The current x value
+/// * `y` - The current y (state) value
+/// * `h` - The interval to integrate over
+///
+/// # Returns
+///
+/// The new y value after integrating over the interval h.
+///
+/// # Notes
+/// * See: G. J. Cooper and J. H. Verner, "Some Explicit Runge-Kutta
+///   Methods of High Order", SIAM J. Numer. Anal. 9 (1972)
+///
+/// # Example
+///
+/// ```
+/// use satctrl::rk8_integrate;
+/// use satctrl::Vector2;
+/// // 1D harmonic oscillator. 1st state is position, 2nd is velocity.
+/// let f = |x: f64, y: &Vector2| Vector2::from_vec([y[1], -y[0]]);
+/// let y = Vector2::from_vec([1.0, 0.0]);
+/// let y_new = rk8_integrate(f, 0.0, y, 0.1);
+/// assert!((y_new[0] - 0.1_f64.cos()).abs() < 1.0e-14);
+/// ```
+///
+pub fn rk8_integrate<F, S>(f: F, x: f64, y: S, h: f64) -> S
+where
+    F: Fn(f64, &S) -> S,
+    S: std::ops::Div<f64, Output = S>
+        + std::ops::Mul<f64, Output = S>
+        + std::ops::Add<S, Output = S>
+        + Clone,
+{
+    let s = 21.0_f64.sqrt();
+    let c = [
+        0.0,
+        0.5,
+        0.5,
+        (7.0 + s) / 14.0,
+        (7.0 + s) / 14.0,
+        0.5,
+        (7.0 - s) / 14.0,
+        (7.0 - s) / 14.0,
+        0.5,
+        (7.0 + s) / 14.0,
+        1.0,
+    ];
+    let a: [&[f64]; 11] = [
+        &[],
+        &[0.5],
+        &[0.25, 0.25],
+        &[1.0 / 7.0, (-7.0 - 3.0 * s) / 98.0, (21.0 + 5.0 * s) / 49.0],
+        &[
+            (11.0 + s) / 84.0,
+            0.0,
+            (18.0 + 4.0 * s) / 63.0,
+            (21.0 - s) / 252.0,
+        ],
+        &[
+            (5.0 + s) / 48.0,
+            0.0,
+            (9.0 + s) / 36.0,
+            (-231.0 + 14.0 * s) / 360.0,
+            (63.0 - 7.0 * s) / 80.0,
+        ],
+        &[
+            (10.0 - s) / 42.0,
+            0.0,
+            (-432.0 + 92.0 * s) / 315.0,
+            (633.0 - 145.0 * s) / 90.0,
+            (-504.0 + 115.0 * s) / 70.0,
+            (63.0 - 13.0 * s) / 35.0,
+        ],
+        &[
+            1.0 / 14.0,
+            0.0,
+            0.0,
+            0.0,
+            (14.0 - 3.0 * s) / 126.0,
+            (13.0 - 3.0 * s) / 63.0,
+            1.0 / 9.0,
+        ],
+        &[
+            1.0 / 32.0,
+            0.0,
+            0.0,
+            0.0,
+            (91.0 - 21.0 * s) / 576.0,
+            11.0 / 72.0,
+            (-385.0 - 75.0 * s) / 1152.0,
+            (63.0 + 13.0 * s) / 128.0,
+        ],
+        &[
+            1.0 / 14.0,
+            0.0,
+            0.0,
+            0.0,
+            1.0 / 9.0,
+            (-733.0 - 147.0 * s) / 2205.0,
+            (515.0 + 111.0 * s) / 504.0,
+            (-51.0 - 11.0 * s) / 56.0,
+            (132.0 + 28.0 * s) / 245.0,
+        ],
+        &[
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            (-42.0 + 7.0 * s) / 18.0,
+            (-18.0 + 28.0 * s) / 45.0,
+            (-273.0 - 53.0 * s) / 72.0,
+            (301.0 + 53.0 * s) / 72.0,
+            (28.0 - 28.0 * s) / 45.0,
+            (49.0 - 7.0 * s) / 18.0,
+        ],
+    ];
+
+    let mut k = Vec::<S>::with_capacity(11);
+    for (ci, ai) in c.iter().zip(a.iter()) {
+        let mut yi = y.clone();
+        for (kj, aij) in k.iter().zip(ai.iter()) {
+            if *aij != 0.0 {
+                yi = yi + kj.clone() * *aij;
+            }
+        }
+        k.push(f(x + ci * h, &yi) * h);
+    }
+    let k11 = k.pop().unwrap();
+    let k10 = k.pop().unwrap();
+    let k9 = k.pop().unwrap();
+    let k8 = k.pop().unwrap();
+    let k1 = k.swap_remove(0);
+    y + (k1 * 9.0 + k8 * 49.0 + k9 * 64.0 + k10 * 49.0 + k11 * 9.0) / 180.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rk4_integrate;
+
+    type Integrator = fn(fn(f64, &f64) -> f64, f64, f64, f64) -> f64;
+
+    /// Global error integrating y' = -y from 0 to 2 in n steps
+    fn error(integrate: Integrator, n: usize) -> f64 {
+        let h = 2.0 / n as f64;
+        let mut y = 1.0;
+        for i in 0..n {
+            y = integrate(|_x, y| -y, i as f64 * h, y, h);
+        }
+        (y - (-2.0_f64).exp()).abs()
+    }
+
+    #[test]
+    fn test_rk8_order() {
+        let rk4 = |f, x, y, h| rk4_integrate(f, x, y, h);
+        let rk8 = |f, x, y, h| rk8_integrate(f, x, y, h);
+
+        // Far more accurate than RK4 at the same step
+        assert!(error(rk8, 4) < error(rk4, 4) * 1.0e-3);
+
+        // Halving the step reduces the error by ~2^8
+        let ratio = error(rk8, 4) / error(rk8, 8);
+        assert!(ratio > 200.0 && ratio < 600.0);
+    }
+
+    #[test]
+    fn test_rk8_nonautonomous() {
+        // y' = y cos(x), y = exp(sin(x))
+        let mut y = 1.0;
+        let h = 0.1;
+        for i in 0..40 {
+            y = rk8_integrate(|x, y: &f64| y * x.cos(), i as f64 * h, y, h);
+        }
+        assert!((y - 4.0_f64.sin().exp()).abs() < 1.0e-11);
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{SCError, SCResult};
+
+/// Butcher tableau of an explicit Runge-Kutta method with `S` stages
+///
+/// Stage `i` evaluates the derivative at `t + c[i] h` and
+/// `y + h Σ_j a[i][j] k_j`; the step is `y + h Σ_i b[i] k_i`. Only the
+/// strictly lower triangle of `a` is used, since the method is explicit.
+///
+/// # Example
+///
+/// ```
+/// use satctrl::ButcherTableau;
+/// // Heun's method
+/// let heun = ButcherTableau {
+///     a: [[0.0, 0.0], [1.0, 0.0]],
+///     b: [0.5, 0.5],
+///     c: [0.0, 1.0],
+/// };
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct ButcherTableau<const S: usize> {
+    /// Runge-Kutta matrix
+    pub a: [[f64; S]; S],
+    /// Weights
+    pub b: [f64; S],
+    /// Nodes
+    pub c: [f64; S],
+}
+
+impl ButcherTableau<4> {
+    /// The classic 4th-order Runge-Kutta method
+    pub const RK4: Self = Self {
+        a: [
+            [0.0, 0.0, 0.0, 0.0],
+            [0.5, 0.0, 0.0, 0.0],
+            [0.0, 0.5, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+        ],
+        b: [1.0 / 6.0, 1.0 / 3.0, 1.0 / 3.0, 1.0 / 6.0],
+        c: [0.0, 0.5, 0.5, 1.0],
+    };
+}
+
+impl<const S: usize> ButcherTableau<S> {
+    /// Take a single step of the method
+    ///
+    /// # Arguments
+    /// * `f` - The function to integrate (dy/dt)
+    /// * `t` - The current time
+    /// * `y` - The current state
+    /// * `h` - The step size
+    ///
+    /// # Returns
+    /// The state at `t + h`
+    ///
+    pub fn step<F, Y>(&self, f: &F, t: f64, y: Y, h: f64) -> Y
+    where
+        F: Fn(f64, &Y) -> Y,
+        Y: std::ops::Mul<f64, Output = Y> + std::ops::Add<Y, Output = Y> + Clone,
+    {
+        // Stage derivatives, scaled by h
+        let mut k: Vec<Y> = Vec::with_capacity(S);
+        for i in 0..S {
+            let yi = (0..i)
+                .filter(|&j| self.a[i][j] != 0.0)
+                .fold(y.clone(), |acc, j| acc + k[j].clone() * self.a[i][j]);
+            k.push(f(t + self.c[i] * h, &yi) * h);
+        }
+        k.into_iter()
+            .zip(self.b.iter())
+            .filter(|(_, b)| **b != 0.0)
+            .fold(y, |acc, (ki, b)| acc + ki * *b)
+    }
+}
+
+/// Explicit Runge-Kutta method with a user-supplied Butcher tableau
+///
+/// Integrate from `t0` to `t1` with fixed steps of `dt`, shortening the
+/// last step to end exactly at `t1`.
+///
+/// # Arguments
+/// * `tableau` - The Butcher tableau of the method
+/// * `f` - The function to integrate (dy/dt)
+/// * `y0` - The starting state
+/// * `t0` - The starting time
+/// * `t1` - The final time; may be less than `t0`
+/// * `dt` - The step size (magnitude)
+///
+/// # Returns
+/// The state at `t1`, or `SCError::InvalidInput` if `dt` is not finite
+/// and positive
+///
+/// # Example
+///
+/// ```
+/// use satctrl::{rk_integrate, ButcherTableau, Vector2};
+/// // 1D harmonic oscillator. 1st state is position, 2nd is velocity.
+/// let f = |_t: f64, y: &Vector2| Vector2::from_vec([y[1], -y[0]]);
+/// let y0 = Vector2::from_vec([1.0, 0.0]);
+/// let y = rk_integrate(&ButcherTableau::RK4, f, y0, 0.0, 1.0, 0.01).unwrap();
+/// assert!((y[0] - 1.0_f64.cos()).abs() < 1.0e-9);
+/// ```
+///
+pub fn rk_integrate<const S: usize, F, Y>(
+    tableau: &ButcherTableau<S>,
+    f: F,
+    y0: Y,
+    t0: f64,
+    t1: f64,
+    dt: f64,
+) -> SCResult<Y>
+where
+    F: Fn(f64, &Y) -> Y,
+    Y: std::ops::Mul<f64, Output = Y> + std::ops::Add<Y, Output = Y> + Clone,
+{
+    if !(dt.is_finite() && dt > 0.0) {
+        return Err(SCError::InvalidInput);
+    }
+    let dir = (t1 - t0).signum();
+    let mut t = t0;
+    let mut y = y0;
+    while (t1 - t) * dir > 0.0 {
+        let last = (t1 - t).abs() <= dt;
+        let h = if last { t1 - t } else { dir * dt };
+        y = tableau.step(&f, t, y, h);
+        t = if last { t1 } else { t + h };
+    }
+    Ok(y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rk4_integrate, Vector2};
+
+    #[test]
+    fn test_rk_tableau() {
+        let f = |t: f64, y: &Vector2| Vector2::from_vec([y[1], -y[0] + 0.1 * t.sin()]);
+        let y0 = Vector2::from_vec([1.0, -0.5]);
+
+        // Matches the hard-coded RK4 step to round-off
+        let mut y = y0;
+        let mut yt = y0;
+        for i in 0..50 {
+            let t = i as f64 * 0.1;
+            y = rk4_integrate(f, t, y, 0.1);
+            yt = ButcherTableau::RK4.step(&f, t, yt, 0.1);
+            assert!((y - yt).norm() < 1.0e-14);
+        }
+        let yr = rk_integrate(&ButcherTableau::RK4, f, y0, 0.0, 5.0, 0.1).unwrap();
+        assert!((yr - y).norm() < 1.0e-13);
+
+        // Forward Euler, with a short last step
+        let euler = ButcherTableau {
+            a: [[0.0]],
+            b: [1.0],
+            c: [0.0],
+        };
+        let y = rk_integrate(&euler, |_t, y: &f64| -y, 1.0, 0.0, 0.25, 0.1).unwrap();
+        assert!((y - 0.9 * 0.9 * 0.95).abs() < 1.0e-15);
+
+        assert!(matches!(
+            rk_integrate(&euler, |_t, y: &f64| -y, 1.0, 0.0, 1.0, 0.0),
+            Err(SCError::InvalidInput)
+        ));
+    }
+}
@@ -0,0 +1,302 @@
+//! Symmetric matrices stored as their lower triangle
+//!
+//! Covariance matrices computed by a filter drift away from symmetry as
+//! rounding errors accumulate. Storing only the lower triangle makes the
+//! symmetry structural, and halves the storage of large matrices.
+
+use super::Matrix;
+
+/// Symmetric `M` x `M` matrix, storing the `L = M (M + 1) / 2` elements
+/// of its lower triangle
+///
+/// Stable Rust cannot express `L` in terms of `M`, so it is a separate
+/// const parameter, checked at compile time. Use the `SymmetricMatrix2`
+/// through `SymmetricMatrix6` aliases for the common sizes.
+///
+/// Element (i, j) and element (j, i) are the same stored value, so every
+/// operation preserves symmetry exactly.
+///
+/// # Example
+/// ```
+/// use satctrl::{Matrix3, SymmetricMatrix3};
+/// let mut p = SymmetricMatrix3::identity() * 4.0;
+/// p[(2, 0)] = 1.0;
+/// assert_eq!(p[(0, 2)], 1.0);
+/// let m: Matrix3 = p.to_matrix();
+/// assert_eq!(m, m.transpose());
+/// ```
+///
+#[derive(Clone, Copy, Debug)]
+pub struct SymmetricMatrix<const M: usize, const L: usize> {
+    data: [f64; L], // lower triangle, row by row
+}
+
+/// Some common symmetric matrix types
+pub type SymmetricMatrix2 = SymmetricMatrix<2, 3>;
+pub type SymmetricMatrix3 = SymmetricMatrix<3, 6>;
+pub type SymmetricMatrix4 = SymmetricMatrix<4, 10>;
+pub type SymmetricMatrix5 = SymmetricMatrix<5, 15>;
+pub type SymmetricMatrix6 = SymmetricMatrix<6, 21>;
+
+/// Offset of element (row, col) in the packed lower triangle
+fn packed_index(row: usize, col: usize) -> usize {
+    let (i, j) = if row >= col { (row, col) } else { (col, row) };
+    i * (i + 1) / 2 + j
+}
+
+impl<const M: usize, const L: usize> SymmetricMatrix<M, L> {
+    /// Create a matrix of zeros
+    pub fn zeros() -> Self {
+        const { assert!(L == M * (M + 1) / 2, "L must equal M * (M + 1) / 2") };
+        Self { data: [0.0; L] }
+    }
+
+    /// Create an identity matrix
+    pub fn identity() -> Self {
+        let mut s = Self::zeros();
+        for i in 0..M {
+            s.data[packed_index(i, i)] = 1.0;
+        }
+        s
+    }
+
+    /// Create a symmetric matrix from a full matrix
+    ///
+    /// # Arguments
+    /// * `m` - The matrix to convert
+    ///
+    /// # Returns
+    /// The symmetric part `(m + mᵀ) / 2`; equal to `m` if `m` is symmetric
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::{Matrix2, SymmetricMatrix2};
+    /// let m = Matrix2::from_row_major_array([[1.0, 2.0], [4.0, 3.0]]);
+    /// let s = SymmetricMatrix2::from_matrix(&m);
+    /// assert_eq!(s[(0, 1)], 3.0);
+    /// ```
+    ///
+    pub fn from_matrix(m: &Matrix<M, M>) -> Self {
+        let mut s = Self::zeros();
+        for i in 0..M {
+            for j in 0..=i {
+                s.data[packed_index(i, j)] = 0.5 * (m[(i, j)] + m[(j, i)]);
+            }
+        }
+        s
+    }
+
+    /// Expand to a full matrix
+    pub fn to_matrix(&self) -> Matrix<M, M> {
+        let mut m = Matrix::<M, M>::zeros();
+        for i in 0..M {
+            for j in 0..M {
+                m[(i, j)] = self.data[packed_index(i, j)];
+            }
+        }
+        m
+    }
+
+    /// The packed lower triangle, row by row
+    pub fn as_packed_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    /// Symmetric congruence transform `A S Aᵀ`
+    ///
+    /// The output size `P` x `P` is set by `A`; its packed length `Q` is
+    /// checked at compile time. The result is symmetric by construction,
+    /// e.g. for propagating a covariance through a state transition matrix
+    ///
+    /// # Arguments
+    /// * `a` - The transform matrix
+    ///
+    /// # Returns
+    /// The transformed symmetric matrix
+    ///
+    /// # Example
+    /// ```
+    /// use satctrl::{Matrix2, SymmetricMatrix2};
+    /// let phi = Matrix2::from_row_major_array([[1.0, 1.0], [0.0, 1.0]]);
+    /// let p: SymmetricMatrix2 = SymmetricMatrix2::identity().transform(&phi);
+    /// assert_eq!(p[(0, 0)], 2.0);
+    /// ```
+    ///
+    pub fn transform<const P: usize, const Q: usize>(
+        &self,
+        a: &Matrix<P, M>,
+    ) -> SymmetricMatrix<P, Q> {
+        let sa = self.to_matrix() * a.transpose();
+        let mut out = SymmetricMatrix::<P, Q>::zeros();
+        for i in 0..P {
+            for j in 0..=i {
+                out.data[packed_index(i, j)] = (0..M).map(|k| a[(i, k)] * sa[(k, j)]).sum();
+            }
+        }
+        out
+    }
+}
+
+/// Compare element-wise with the same tolerance as `Matrix`
+impl<const M: usize, const L: usize> PartialEq for SymmetricMatrix<M, L> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .all(|(a, b)| (a - b).abs() <= f64::EPSILON * 5.0)
+    }
+}
+
+/// Element at (row, col); (col, row) refers to the same element
+impl<const M: usize, const L: usize> std::ops::Index<(usize, usize)> for SymmetricMatrix<M, L> {
+    type Output = f64;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        assert!(index.0 < M && index.1 < M, "index out of range");
+        &self.data[packed_index(index.0, index.1)]
+    }
+}
+
+/// Mutable element at (row, col); setting it also sets (col, row)
+impl<const M: usize, const L: usize> std::ops::IndexMut<(usize, usize)> for SymmetricMatrix<M, L> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        assert!(index.0 < M && index.1 < M, "index out of range");
+        &mut self.data[packed_index(index.0, index.1)]
+    }
+}
+
+impl<const M: usize, const L: usize> From<SymmetricMatrix<M, L>> for Matrix<M, M> {
+    fn from(s: SymmetricMatrix<M, L>) -> Self {
+        s.to_matrix()
+    }
+}
+
+/// Add two symmetric matrices
+impl<const M: usize, const L: usize> std::ops::Add for SymmetricMatrix<M, L> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+/// Subtract two symmetric matrices
+impl<const M: usize, const L: usize> std::ops::Sub for SymmetricMatrix<M, L> {
+    type Output = Self;
+
+    fn sub(mut self, other: Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<const M: usize, const L: usize> std::ops::AddAssign for SymmetricMatrix<M, L> {
+    fn add_assign(&mut self, other: Self) {
+        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
+            *a += b;
+        }
+    }
+}
+
+impl<const M: usize, const L: usize> std::ops::SubAssign for SymmetricMatrix<M, L> {
+    fn sub_assign(&mut self, other: Self) {
+        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
+            *a -= b;
+        }
+    }
+}
+
+/// Multiply a symmetric matrix by a scalar
+impl<const M: usize, const L: usize> std::ops::Mul<f64> for SymmetricMatrix<M, L> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: f64) -> Self {
+        self.data.iter_mut().for_each(|x| *x *= rhs);
+        self
+    }
+}
+
+/// Multiply a scalar by a symmetric matrix
+impl<const M: usize, const L: usize> std::ops::Mul<SymmetricMatrix<M, L>> for f64 {
+    type Output = SymmetricMatrix<M, L>;
+
+    fn mul(self, rhs: SymmetricMatrix<M, L>) -> Self::Output {
+        rhs * self
+    }
+}
+
+/// Divide a symmetric matrix by a scalar
+impl<const M: usize, const L: usize> std::ops::Div<f64> for SymmetricMatrix<M, L> {
+    type Output = Self;
+
+    fn div(mut self, rhs: f64) -> Self {
+        self.data.iter_mut().for_each(|x| *x /= rhs);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Matrix3, Matrix6};
+
+    fn is_symmetric<const M: usize>(m: &Matrix<M, M>) -> bool {
+        (0..M).all(|i| (0..M).all(|j| m[(i, j)] == m[(j, i)]))
+    }
+
+    #[test]
+    fn test_symmetric_matrix() {
+        let m =
+            Matrix3::from_row_major_array([[4.0, 1.0, -2.0], [1.0, 5.0, 0.5], [-2.0, 0.5, 6.0]]);
+        let s = SymmetricMatrix3::from_matrix(&m);
+        assert_eq!(s.to_matrix(), m);
+        assert_eq!(s.as_packed_slice(), &[4.0, 1.0, 5.0, -2.0, 0.5, 6.0]);
+        assert_eq!(s[(0, 2)], -2.0);
+        assert_eq!(s[(2, 0)], -2.0);
+
+        // Setting one element sets its mirror
+        let mut t = SymmetricMatrix3::identity();
+        t[(1, 2)] = 0.3;
+        assert_eq!(t[(2, 1)], 0.3);
+
+        // Arithmetic stays symmetric
+        let sum = (s + t) * 1.7 - t / 3.0;
+        let full = sum.to_matrix();
+        assert!(is_symmetric(&full));
+        let expected = (m + t.to_matrix()) * 1.7 - t.to_matrix() / 3.0;
+        assert_eq!(full, expected);
+        assert_eq!(2.0 * s, s + s);
+
+        // An asymmetric input keeps only its symmetric part
+        let a = Matrix3::from_row_major_array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        assert_eq!(
+            SymmetricMatrix3::from_matrix(&a).to_matrix(),
+            a.symmetric_part()
+        );
+    }
+
+    #[test]
+    fn test_symmetric_transform() {
+        // Covariance propagation through many steps stays exactly symmetric
+        let mut phi = Matrix6::identity();
+        for i in 0..3 {
+            phi[(i, i + 3)] = 0.1;
+            phi[(i + 3, i)] = -0.013 * (i + 1) as f64;
+        }
+        let mut p = SymmetricMatrix6::identity() * 2.0;
+        let mut pfull = Matrix6::identity() * 2.0;
+        for _ in 0..20 {
+            p = p.transform(&phi);
+            pfull = phi * pfull * phi.transpose();
+        }
+        assert!(is_symmetric(&p.to_matrix()));
+        let err = p.to_matrix() - pfull;
+        assert!(err.all(|x| x.abs() < 1.0e-12));
+
+        // Non-square transform
+        let h = Matrix::<2, 3>::from_row_major_array([[1.0, 0.0, 1.0], [0.0, 2.0, 0.0]]);
+        let s: SymmetricMatrix<2, 3> = SymmetricMatrix3::identity().transform(&h);
+        assert_eq!(s.to_matrix(), h * h.transpose());
+    }
+}
@@ -0,0 +1,69 @@
+//! Physical constants for the Earth, Sun, and Moon
+//!
+//! All values are in SI units (meters, seconds, radians)
+//!
+
+/// Earth gravitational parameter, m^3/s^2
+///
+/// Source: WGS84 (NGA.STND.0036), including the atmosphere
+pub const MU_EARTH: f64 = 3.986004418e14;
+
+/// WGS84 semi-major axis (equatorial radius), meters
+///
+/// Source: WGS84 (NGA.STND.0036)
+pub const R_EARTH_WGS84: f64 = 6_378_137.0;
+
+/// WGS84 flattening
+///
+/// Source: WGS84 (NGA.STND.0036)
+pub const F_EARTH_WGS84: f64 = 1.0 / 298.257223563;
+
+/// Mean radius of the Earth, meters
+///
+/// Source: IUGG mean radius R1 = (2a + b) / 3 of the WGS84 ellipsoid
+pub const R_EARTH_MEAN: f64 = 6_371_008.8;
+
+/// Earth second zonal harmonic (unnormalized), dimensionless
+///
+/// Source: EGM96, J2 = -√5 C̄20
+pub const J2_EARTH: f64 = 1.082_626_683_553_15e-3;
+
+/// Earth mean rotation rate, rad/s
+///
+/// Source: WGS84 (NGA.STND.0036)
+pub const OMEGA_EARTH: f64 = 7.292115e-5;
+
+/// Sun gravitational parameter, m^3/s^2
+///
+/// Source: JPL DE440
+pub const MU_SUN: f64 = 1.32712440041279e20;
+
+/// Nominal solar radius, meters
+///
+/// Source: IAU 2015 Resolution B3
+pub const R_SUN: f64 = 6.957e8;
+
+/// Nominal total solar irradiance at 1 AU, W/m^2
+///
+/// Source: IAU 2015 Resolution B3
+pub const SOLAR_IRRADIANCE: f64 = 1361.0;
+
+/// Moon gravitational parameter, m^3/s^2
+///
+/// Source: JPL DE440
+pub const MU_MOON: f64 = 4.902800118e12;
+
+/// Mean radius of the Moon, meters
+///
+/// Source: IAU WGCCRE 2015 report
+pub const R_MOON: f64 = 1_737_400.0;
+
+/// Astronomical unit, meters
+///
+/// Source: IAU 2012 Resolution B2
+pub const AU: f64 = 149_597_870_700.0;
+
+/// Speed of light in vacuum, m/s
+///
+/// Source: SI definition
+pub const C_LIGHT: f64 = 299_792_458.0;
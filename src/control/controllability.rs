@@ -0,0 +1,164 @@
+use crate::Matrix;
+
+/// Controllability matrix `[B, AB, A²B, ..., Aᴺ⁻¹B]` of the pair (A, B)
+///
+/// Stable Rust cannot express the `N * M` output width, so it is a
+/// separate const parameter `P`, checked at compile time to equal `N * M`
+///
+/// # Arguments
+/// * `a` - The N x N state matrix
+/// * `b` - The N x M input matrix
+///
+/// # Returns
+/// The N x (N * M) controllability matrix
+///
+/// # Example
+/// ```
+/// use satctrl::control::controllability_matrix;
+/// use satctrl::Matrix;
+/// let a = Matrix::<2, 2>::from_row_major_array([[0.0, 1.0], [0.0, 0.0]]);
+/// let b = Matrix::<2, 1>::from_col_major_array([[0.0, 1.0]]);
+/// let c = controllability_matrix::<2, 1, 2>(&a, &b);
+/// assert_eq!(c.rank(1.0e-12), 2);
+/// ```
+///
+pub fn controllability_matrix<const N: usize, const M: usize, const P: usize>(
+    a: &Matrix<N, N>,
+    b: &Matrix<N, M>,
+) -> Matrix<N, P> {
+    const { assert!(P == N * M, "P must equal N * M") };
+    let mut c = Matrix::<N, P>::zeros();
+    let mut block = *b;
+    for k in 0..N {
+        for j in 0..M {
+            for i in 0..N {
+                c[(i, k * M + j)] = block[(i, j)];
+            }
+        }
+        block = *a * block;
+    }
+    c
+}
+
+/// Observability matrix `[C; CA; CA²; ...; CAᴺ⁻¹]` of the pair (A, C)
+///
+/// Stable Rust cannot express the `N * M` output height, so it is a
+/// separate const parameter `P`, checked at compile time to equal `N * M`
+///
+/// # Arguments
+/// * `a` - The N x N state matrix
+/// * `c` - The M x N output matrix
+///
+/// # Returns
+/// The (N * M) x N observability matrix
+///
+/// # Example
+/// ```
+/// use satctrl::control::observability_matrix;
+/// use satctrl::Matrix;
+/// let a = Matrix::<2, 2>::from_row_major_array([[0.0, 1.0], [0.0, 0.0]]);
+/// let c = Matrix::<1, 2>::from_row_major_array([[1.0, 0.0]]);
+/// let o = observability_matrix::<2, 1, 2>(&a, &c);
+/// assert_eq!(o.rank(1.0e-12), 2);
+/// ```
+///
+pub fn observability_matrix<const N: usize, const M: usize, const P: usize>(
+    a: &Matrix<N, N>,
+    c: &Matrix<M, N>,
+) -> Matrix<P, N> {
+    const { assert!(P == N * M, "P must equal N * M") };
+    let mut o = Matrix::<P, N>::zeros();
+    let mut block = *c;
+    for k in 0..N {
+        for i in 0..M {
+            for j in 0..N {
+                o[(k * M + i, j)] = block[(i, j)];
+            }
+        }
+        block = block * *a;
+    }
+    o
+}
+
+/// Whether the pair (A, B) is controllable
+///
+/// # Arguments
+/// * `a` - The N x N state matrix
+/// * `b` - The N x M input matrix
+/// * `tol` - Rank tolerance, see `Matrix::rank`
+///
+/// # Returns
+/// True if the controllability matrix has full rank N
+///
+pub fn is_controllable<const N: usize, const M: usize, const P: usize>(
+    a: &Matrix<N, N>,
+    b: &Matrix<N, M>,
+    tol: f64,
+) -> bool {
+    controllability_matrix::<N, M, P>(a, b).rank(tol) == N
+}
+
+/// Whether the pair (A, C) is observable
+///
+/// # Arguments
+/// * `a` - The N x N state matrix
+/// * `c` - The M x N output matrix
+/// * `tol` - Rank tolerance, see `Matrix::rank`
+///
+/// # Returns
+/// True if the observability matrix has full rank N
+///
+pub fn is_observable<const N: usize, const M: usize, const P: usize>(
+    a: &Matrix<N, N>,
+    c: &Matrix<M, N>,
+    tol: f64,
+) -> bool {
+    observability_matrix::<N, M, P>(a, c).rank(tol) == N
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_controllability() {
+        // Double integrator driven by force is controllable
+        let a = Matrix::<2, 2>::from_row_major_array([[0.0, 1.0], [0.0, 0.0]]);
+        let b = Matrix::<2, 1>::from_row_major_array([[0.0], [1.0]]);
+        let c = controllability_matrix::<2, 1, 2>(&a, &b);
+        assert_eq!(
+            c,
+            Matrix::<2, 2>::from_row_major_array([[0.0, 1.0], [1.0, 0.0]])
+        );
+        assert!(is_controllable::<2, 1, 2>(&a, &b, 1.0e-12));
+
+        // Two decoupled identical modes driven by the same input are not
+        let a = Matrix::<3, 3>::from_row_major_array([
+            [-1.0, 0.0, 0.0],
+            [0.0, -1.0, 0.0],
+            [0.0, 0.0, -2.0],
+        ]);
+        let b = Matrix::<3, 1>::from_row_major_array([[1.0], [1.0], [1.0]]);
+        assert!(!is_controllable::<3, 1, 3>(&a, &b, 1.0e-12));
+
+        // A second input restores controllability
+        let b2 = Matrix::<3, 2>::from_row_major_array([[1.0, 0.0], [1.0, 1.0], [1.0, 0.0]]);
+        assert!(is_controllable::<3, 2, 6>(&a, &b2, 1.0e-12));
+    }
+
+    #[test]
+    fn test_observability() {
+        let a = Matrix::<2, 2>::from_row_major_array([[0.0, 1.0], [0.0, 0.0]]);
+        // Position measurement observes the double integrator...
+        let c = Matrix::<1, 2>::from_row_major_array([[1.0, 0.0]]);
+        assert!(is_observable::<2, 1, 2>(&a, &c, 1.0e-12));
+        // ...velocity measurement cannot recover position
+        let c = Matrix::<1, 2>::from_row_major_array([[0.0, 1.0]]);
+        let o = observability_matrix::<2, 1, 2>(&a, &c);
+        assert_eq!(
+            o,
+            Matrix::<2, 2>::from_row_major_array([[0.0, 1.0], [0.0, 0.0]])
+        );
+        assert!(!is_observable::<2, 1, 2>(&a, &c, 1.0e-12));
+    }
+}
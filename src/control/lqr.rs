@@ -0,0 +1,275 @@
+use crate::Matrix;
+use crate::{SCError, SCResult};
+
+/// Structured doubling algorithm
+///
+/// Returns the stabilizing solution X of the discrete algebraic Riccati
+/// equation in the form `X = Aᵀ X (I + G X)⁻¹ A + H`
+///
+/// See: E. K.-W. Chu, H.-Y. Fan, W.-W. Lin, "A structure-preserving
+/// doubling algorithm for continuous-time algebraic Riccati equations",
+/// Linear Algebra Appl. 396 (2005)
+fn sda<const N: usize>(
+    a: Matrix<N, N>,
+    g: Matrix<N, N>,
+    h: Matrix<N, N>,
+) -> SCResult<Matrix<N, N>> {
+    let eye = Matrix::<N, N>::identity();
+    let (mut a, mut g, mut h) = (a, g, h);
+    for _iter in 0..100 {
+        let w = (eye + g * h).inverse().ok_or(SCError::MatrixIsSingular)?;
+        let aw = a * w;
+        let hnext = h + a.transpose() * h * w * a;
+        g = g + aw * g * a.transpose();
+        a = aw * a;
+        let dh = hnext - h;
+        let delta = dh.frobenius_inner(&dh).sqrt();
+        h = hnext;
+        if !delta.is_finite() {
+            return Err(SCError::NotConverged);
+        }
+        if delta <= 1.0e-14 * h.frobenius_inner(&h).sqrt() {
+            return Ok(h.symmetric_part());
+        }
+    }
+    Err(SCError::NotConverged)
+}
+
+/// Solve the continuous algebraic Riccati equation
+///
+/// Finds the stabilizing solution X of `AᵀX + XA - XBR⁻¹BᵀX + Q = 0`
+///
+/// # Arguments
+/// * `a` - The N x N state matrix
+/// * `b` - The N x M input matrix
+/// * `q` - The N x N symmetric positive semi-definite state weight
+/// * `r` - The M x M symmetric positive definite input weight
+///
+/// # Returns
+/// The symmetric solution X, `SCError::MatrixIsSingular` if R cannot be
+/// inverted, or `SCError::NotConverged` if no stabilizing solution was found
+///
+/// # Notes
+/// * Solved with a Cayley transform to a discrete problem and the
+///   structure-preserving doubling algorithm
+///
+pub fn care<const N: usize, const M: usize>(
+    a: &Matrix<N, N>,
+    b: &Matrix<N, M>,
+    q: &Matrix<N, N>,
+    r: &Matrix<M, M>,
+) -> SCResult<Matrix<N, N>> {
+    let rinv = r.inverse().ok_or(SCError::MatrixIsSingular)?;
+    let g = *b * rinv * b.transpose();
+
+    // Cayley transform parameter; any gamma > 0 that is not an eigenvalue
+    // of A works. The Frobenius norm bounds the spectral radius, so adding
+    // one keeps gamma clear of the eigenvalues while on the scale of A
+    let gamma = a.frobenius_inner(a).sqrt() + 1.0;
+    let agam = *a - Matrix::<N, N>::identity() * gamma;
+    let agam_inv = agam.inverse().ok_or(SCError::NotConverged)?;
+    let winv = (agam + g * agam_inv.transpose() * *q)
+        .inverse()
+        .ok_or(SCError::NotConverged)?;
+    let vinv = (agam.transpose() + *q * agam_inv * g)
+        .inverse()
+        .ok_or(SCError::NotConverged)?;
+
+    let a0 = Matrix::<N, N>::identity() + winv * (2.0 * gamma);
+    let g0 = agam_inv * g * vinv * (2.0 * gamma);
+    let h0 = vinv * *q * agam_inv * (2.0 * gamma);
+    sda(a0, g0, h0)
+}
+
+/// Solve the discrete algebraic Riccati equation
+///
+/// Finds the stabilizing solution X of
+/// `X = AᵀXA - AᵀXB(R + BᵀXB)⁻¹BᵀXA + Q`
+///
+/// # Arguments
+/// * `a` - The N x N state transition matrix
+/// * `b` - The N x M input matrix
+/// * `q` - The N x N symmetric positive semi-definite state weight
+/// * `r` - The M x M symmetric positive definite input weight
+///
+/// # Returns
+/// The symmetric solution X, `SCError::MatrixIsSingular` if R cannot be
+/// inverted, or `SCError::NotConverged` if no stabilizing solution was found
+///
+pub fn dare<const N: usize, const M: usize>(
+    a: &Matrix<N, N>,
+    b: &Matrix<N, M>,
+    q: &Matrix<N, N>,
+    r: &Matrix<M, M>,
+) -> SCResult<Matrix<N, N>> {
+    let rinv = r.inverse().ok_or(SCError::MatrixIsSingular)?;
+    sda(*a, *b * rinv * b.transpose(), *q)
+}
+
+/// Continuous-time linear quadratic regulator
+///
+/// Computes the gain K minimizing `∫ xᵀQx + uᵀRu dt` for `ẋ = Ax + Bu`
+/// under the feedback `u = -Kx`
+///
+/// # Arguments
+/// * `a` - The N x N state matrix
+/// * `b` - The N x M input matrix
+/// * `q` - The N x N symmetric positive semi-definite state weight
+/// * `r` - The M x M symmetric positive definite input weight
+///
+/// # Returns
+/// The M x N optimal gain `K = R⁻¹BᵀX`, or an error if the Riccati
+/// equation could not be solved
+///
+/// # Example
+/// ```
+/// use satctrl::control::lqr;
+/// use satctrl::Matrix;
+/// // Double integrator
+/// let a = Matrix::<2, 2>::from_row_major_array([[0.0, 1.0], [0.0, 0.0]]);
+/// let b = Matrix::<2, 1>::from_row_major_array([[0.0], [1.0]]);
+/// let k = lqr(&a, &b, &Matrix::<2, 2>::identity(), &Matrix::<1, 1>::identity()).unwrap();
+/// assert!((k[(0, 0)] - 1.0).abs() < 1.0e-9);
+/// ```
+///
+pub fn lqr<const N: usize, const M: usize>(
+    a: &Matrix<N, N>,
+    b: &Matrix<N, M>,
+    q: &Matrix<N, N>,
+    r: &Matrix<M, M>,
+) -> SCResult<Matrix<M, N>> {
+    let x = care(a, b, q, r)?;
+    let rinv = r.inverse().ok_or(SCError::MatrixIsSingular)?;
+    Ok(rinv * b.transpose() * x)
+}
+
+/// Discrete-time linear quadratic regulator
+///
+/// Computes the gain K minimizing `Σ xᵀQx + uᵀRu` for
+/// `x[k+1] = Ax[k] + Bu[k]` under the feedback `u = -Kx`
+///
+/// # Arguments
+/// * `a` - The N x N state transition matrix
+/// * `b` - The N x M input matrix
+/// * `q` - The N x N symmetric positive semi-definite state weight
+/// * `r` - The M x M symmetric positive definite input weight
+///
+/// # Returns
+/// The M x N optimal gain `K = (R + BᵀXB)⁻¹BᵀXA`, or an error if the
+/// Riccati equation could not be solved
+///
+pub fn dlqr<const N: usize, const M: usize>(
+    a: &Matrix<N, N>,
+    b: &Matrix<N, M>,
+    q: &Matrix<N, N>,
+    r: &Matrix<M, M>,
+) -> SCResult<Matrix<M, N>> {
+    let x = dare(a, b, q, r)?;
+    let btx = b.transpose() * x;
+    let s = (*r + btx * *b).inverse().ok_or(SCError::MatrixIsSingular)?;
+    Ok(s * btx * *a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn double_integrator() -> (Matrix<2, 2>, Matrix<2, 1>) {
+        (
+            Matrix::<2, 2>::from_row_major_array([[0.0, 1.0], [0.0, 0.0]]),
+            Matrix::<2, 1>::from_row_major_array([[0.0], [1.0]]),
+        )
+    }
+
+    #[test]
+    fn test_lqr_double_integrator() {
+        let (a, b) = double_integrator();
+        let q = Matrix::<2, 2>::identity();
+        let r = Matrix::<1, 1>::identity();
+
+        // Analytic: X = [[√3, 1], [1, √3]], K = [1, √3]
+        let s3 = 3.0_f64.sqrt();
+        let x = care(&a, &b, &q, &r).unwrap();
+        let xexp = Matrix::<2, 2>::from_row_major_array([[s3, 1.0], [1.0, s3]]);
+        let err = x - xexp;
+        assert!(err.frobenius_inner(&err).sqrt() < 1.0e-9);
+
+        let k = lqr(&a, &b, &q, &r).unwrap();
+        assert!((k[(0, 0)] - 1.0).abs() < 1.0e-9);
+        assert!((k[(0, 1)] - s3).abs() < 1.0e-9);
+
+        // Closed-loop poles (-√3 ± i) / 2: characteristic s² + √3 s + 1
+        let acl = a - b * k;
+        assert!((acl.trace() + s3).abs() < 1.0e-9);
+        assert!((acl.determinant() - 1.0).abs() < 1.0e-9);
+    }
+
+    #[test]
+    fn test_care_residual() {
+        let a = Matrix::<3, 3>::from_row_major_array([
+            [0.5, 1.0, 0.0],
+            [-1.0, 0.2, 2.0],
+            [0.0, 0.3, -1.5],
+        ]);
+        let b = Matrix::<3, 2>::from_row_major_array([[1.0, 0.0], [0.0, 0.0], [0.5, 1.0]]);
+        let q = Matrix::<3, 3>::identity() * 2.0;
+        let r = Matrix::<2, 2>::from_row_major_array([[1.0, 0.2], [0.2, 0.5]]);
+        let x = care(&a, &b, &q, &r).unwrap();
+        let res = a.transpose() * x + x * a - x * b * r.inverse().unwrap() * b.transpose() * x + q;
+        assert!(res.frobenius_inner(&res) < 1.0e-16 * x.frobenius_inner(&x));
+    }
+
+    #[test]
+    fn test_care_unstable() {
+        // Scalar a = 2: 4x - x² + 1 = 0, so x = 2 + √5. A shift equal to
+        // the eigenvalue 2 would make the Cayley transform singular
+        let one = Matrix::<1, 1>::identity();
+        let x = care(&(one * 2.0), &one, &one, &one).unwrap();
+        assert!((x[(0, 0)] - (2.0 + 5.0_f64.sqrt())).abs() < 1.0e-9);
+
+        // Decoupled diag(1, 0): x = 1 + √2 and x = 1
+        let a = Matrix::<2, 2>::from_row_major_array([[1.0, 0.0], [0.0, 0.0]]);
+        let eye = Matrix::<2, 2>::identity();
+        let k = lqr(&a, &eye, &eye, &eye).unwrap();
+        let kexp = Matrix::<2, 2>::from_row_major_array([[1.0 + 2.0_f64.sqrt(), 0.0], [0.0, 1.0]]);
+        let err = k - kexp;
+        assert!(err.frobenius_inner(&err).sqrt() < 1.0e-9);
+    }
+
+    #[test]
+    fn test_dlqr() {
+        // Discretized double integrator
+        let dt = 0.1;
+        let a = Matrix::<2, 2>::from_row_major_array([[1.0, dt], [0.0, 1.0]]);
+        let b = Matrix::<2, 1>::from_row_major_array([[0.5 * dt * dt], [dt]]);
+        let q = Matrix::<2, 2>::identity();
+        let r = Matrix::<1, 1>::identity();
+        let x = dare(&a, &b, &q, &r).unwrap();
+        let btx = b.transpose() * x;
+        let res = a.transpose() * x * a
+            - a.transpose() * x * b * (r + btx * b).inverse().unwrap() * btx * a
+            + q
+            - x;
+        assert!(res.frobenius_inner(&res) < 1.0e-16 * x.frobenius_inner(&x));
+
+        // Closed loop is stable: both eigenvalues inside the unit circle
+        let k = dlqr(&a, &b, &q, &r).unwrap();
+        let acl = a - b * k;
+        let (tr, det) = (acl.trace(), acl.determinant());
+        assert!(det.abs() < 1.0 && tr.abs() < 1.0 + det);
+    }
+
+    #[test]
+    fn test_lqr_singular_r() {
+        let (a, b) = double_integrator();
+        assert!(matches!(
+            lqr(
+                &a,
+                &b,
+                &Matrix::<2, 2>::identity(),
+                &Matrix::<1, 1>::zeros()
+            ),
+            Err(SCError::MatrixIsSingular)
+        ));
+    }
+}
@@ -0,0 +1,7 @@
+mod controllability;
+mod lqr;
+
+pub use controllability::{
+    controllability_matrix, is_controllable, is_observable, observability_matrix,
+};
+pub use lqr::{care, dare, dlqr, lqr};
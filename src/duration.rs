@@ -0,0 +1,304 @@
+//! Signed time durations with microsecond resolution
+
+use std::ops::{Add, Neg, Sub};
+
+const MICROSECONDS_PER_SECOND: i64 = 1_000_000;
+const MICROSECONDS_PER_MINUTE: i64 = 60 * MICROSECONDS_PER_SECOND;
+const MICROSECONDS_PER_HOUR: i64 = 60 * MICROSECONDS_PER_MINUTE;
+const MICROSECONDS_PER_DAY: i64 = 24 * MICROSECONDS_PER_HOUR;
+
+/// Signed span of time, stored as integer microseconds
+///
+/// The range is that of `i64` microseconds, about ±292,000 years. The
+/// floating-point accessors (`as_seconds`, `as_days`, ...) are exact to
+/// the microsecond for spans up to 2^53 µs (about 285 years); longer
+/// spans keep a relative precision of about 1e-16
+///
+/// Equality, ordering, and hashing are those of the microsecond count
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Duration {
+    pub usec: i64,
+}
+
+impl Duration {
+    /// Construct a new Duration from integer microseconds
+    ///
+    /// # Arguments
+    /// * `usec` - Microseconds
+    ///
+    /// # Returns
+    /// A new Duration
+    ///
+    pub const fn from_microseconds(usec: i64) -> Self {
+        Self { usec }
+    }
+
+    /// Construct a new Duration from seconds
+    ///
+    /// # Arguments
+    /// * `s` - Seconds
+    ///
+    /// # Returns
+    /// A new Duration, rounded to the nearest microsecond
+    ///
+    /// # Notes
+    /// Values beyond the range of `i64` microseconds (about ±292,000 years)
+    /// silently saturate to `i64::MIN` / `i64::MAX`, and NaN silently
+    /// becomes zero. The `from_minutes`, `from_hours`, and `from_days`
+    /// constructors share this behavior. A saturated duration is a valid
+    /// value, so `Instant::checked_add` only fails if the sum itself
+    /// overflows. Use `try_from_seconds` or `try_from_days` to reject
+    /// such input instead
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Duration;
+    /// let d = Duration::from_seconds(1.5);
+    /// assert_eq!(d.usec, 1_500_000);
+    /// ```
+    ///
+    pub fn from_seconds(s: f64) -> Self {
+        Self {
+            usec: (s * MICROSECONDS_PER_SECOND as f64).round() as i64,
+        }
+    }
+
+    /// Construct a new Duration from seconds, checking the input
+    ///
+    /// # Arguments
+    /// * `s` - Seconds
+    ///
+    /// # Returns
+    /// A new Duration, rounded to the nearest microsecond, or None if `s`
+    /// is NaN or outside the range of `i64` microseconds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Duration;
+    /// assert_eq!(Duration::try_from_seconds(1.5), Some(Duration::from_seconds(1.5)));
+    /// assert!(Duration::try_from_seconds(f64::NAN).is_none());
+    /// assert!(Duration::try_from_seconds(1.0e14).is_none());
+    /// ```
+    ///
+    pub fn try_from_seconds(s: f64) -> Option<Self> {
+        let usec = (s * MICROSECONDS_PER_SECOND as f64).round();
+        // i64::MAX is not representable; 2^63 is the first value past it
+        if usec >= -(i64::MIN as f64) || usec < i64::MIN as f64 || usec.is_nan() {
+            None
+        } else {
+            Some(Self::from_microseconds(usec as i64))
+        }
+    }
+
+    /// Construct a new Duration from days of 86400 seconds, checking the input
+    ///
+    /// # Arguments
+    /// * `d` - Days
+    ///
+    /// # Returns
+    /// A new Duration, or None if `d` is NaN or outside the range of `i64`
+    /// microseconds (about ±106.75 million days)
+    ///
+    pub fn try_from_days(d: f64) -> Option<Self> {
+        Self::try_from_seconds(d * 86400.0)
+    }
+
+    /// Construct a new Duration from minutes
+    pub fn from_minutes(m: f64) -> Self {
+        Self::from_seconds(m * 60.0)
+    }
+
+    /// Construct a new Duration from hours
+    pub fn from_hours(h: f64) -> Self {
+        Self::from_seconds(h * 3600.0)
+    }
+
+    /// Construct a new Duration from days of 86400 seconds
+    pub fn from_days(d: f64) -> Self {
+        Self::from_seconds(d * 86400.0)
+    }
+
+    /// Duration in seconds
+    pub fn as_seconds(&self) -> f64 {
+        self.usec as f64 / MICROSECONDS_PER_SECOND as f64
+    }
+
+    /// Duration in minutes
+    pub fn as_minutes(&self) -> f64 {
+        self.usec as f64 / MICROSECONDS_PER_MINUTE as f64
+    }
+
+    /// Duration in hours
+    pub fn as_hours(&self) -> f64 {
+        self.usec as f64 / MICROSECONDS_PER_HOUR as f64
+    }
+
+    /// Duration in days of 86400 seconds
+    pub fn as_days(&self) -> f64 {
+        self.usec as f64 / MICROSECONDS_PER_DAY as f64
+    }
+
+    /// Absolute value of the duration
+    pub fn abs(&self) -> Self {
+        Self {
+            usec: self.usec.abs(),
+        }
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+    fn add(self, other: Duration) -> Duration {
+        Duration {
+            usec: self.usec + other.usec,
+        }
+    }
+}
+
+impl Sub for Duration {
+    type Output = Duration;
+    fn sub(self, other: Duration) -> Duration {
+        Duration {
+            usec: self.usec - other.usec,
+        }
+    }
+}
+
+impl Neg for Duration {
+    type Output = Duration;
+    fn neg(self) -> Duration {
+        Duration { usec: -self.usec }
+    }
+}
+
+/// Display the duration as days, hours, minutes, and seconds
+///
+/// Leading components are included once the magnitude reaches them,
+/// zero-valued components are omitted, and negative durations are
+/// shown with a leading minus sign
+///
+/// # Example
+///
+/// ```
+/// use satctrl::Duration;
+/// assert_eq!(Duration::from_hours(-25.5).to_string(), "-1 days 1 hours 30 minutes");
+/// ```
+impl std::fmt::Display for Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.usec < 0 {
+            write!(f, "-")?;
+        }
+        let mut rem = self.usec.unsigned_abs();
+        let mut parts = Vec::<String>::new();
+        for (scale, name) in [
+            (MICROSECONDS_PER_DAY, "days"),
+            (MICROSECONDS_PER_HOUR, "hours"),
+            (MICROSECONDS_PER_MINUTE, "minutes"),
+        ] {
+            let scale = scale as u64;
+            if rem >= scale {
+                parts.push(format!("{} {}", rem / scale, name));
+                rem %= scale;
+            }
+        }
+        if rem > 0 || parts.is_empty() {
+            parts.push(format!(
+                "{} seconds",
+                rem as f64 / MICROSECONDS_PER_SECOND as f64
+            ));
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Duration::from_days(1.0).to_string(), "1 days");
+        assert_eq!(
+            Duration::from_microseconds(MICROSECONDS_PER_DAY + 3_000_000).to_string(),
+            "1 days 3 seconds"
+        );
+        assert_eq!(Duration::from_hours(1.0).to_string(), "1 hours");
+        assert_eq!(
+            Duration::from_seconds(-90.5).to_string(),
+            "-1 minutes 30.5 seconds"
+        );
+        assert_eq!(Duration::from_seconds(0.25).to_string(), "0.25 seconds");
+        assert_eq!(Duration::from_seconds(-0.25).to_string(), "-0.25 seconds");
+        assert_eq!(Duration::from_seconds(0.0).to_string(), "0 seconds");
+        assert_eq!(
+            Duration::from_microseconds(i64::MIN).to_string(),
+            "-106751991 days 4 hours 54.775808 seconds"
+        );
+    }
+
+    #[test]
+    fn test_ops() {
+        let a = Duration::from_minutes(2.0);
+        let b = Duration::from_seconds(30.0);
+        assert_eq!((a + b).as_seconds(), 150.0);
+        assert_eq!((b - a).as_seconds(), -90.0);
+        assert_eq!((-a).abs(), a);
+        assert_eq!(Duration::from_days(0.5).as_hours(), 12.0);
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(
+            Duration::try_from_seconds(-90.5),
+            Some(Duration::from_seconds(-90.5))
+        );
+        assert_eq!(Duration::try_from_days(2.0), Some(Duration::from_days(2.0)));
+        for bad in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1.0e13, -1.0e13] {
+            assert!(Duration::try_from_seconds(bad).is_none());
+        }
+        assert!(Duration::try_from_days(f64::NAN).is_none());
+        assert!(Duration::try_from_days(1.0e12).is_none());
+        assert!(Duration::try_from_days(-1.0e12).is_none());
+
+        // At the i64 boundary; 2^63 µs is just out of range, -2^63 µs is in
+        let edge = 2.0_f64.powi(63) / 1.0e6;
+        assert!(Duration::try_from_seconds(edge).is_none());
+        assert_eq!(
+            Duration::try_from_seconds(-edge),
+            Some(Duration::from_microseconds(i64::MIN))
+        );
+
+        // The unchecked constructors saturate, and map NaN to zero
+        assert_eq!(Duration::from_days(1.0e12).usec, i64::MAX);
+        assert_eq!(Duration::from_days(-1.0e12).usec, i64::MIN);
+        assert_eq!(Duration::from_seconds(f64::NAN).usec, 0);
+    }
+
+    #[test]
+    fn test_ord_hash() {
+        let mut v = vec![
+            Duration::from_hours(1.0),
+            Duration::from_seconds(-5.0),
+            Duration::from_microseconds(1),
+            Duration::from_minutes(60.0),
+            Duration::from_seconds(0.0),
+        ];
+        v.sort();
+        assert_eq!(v[0], Duration::from_seconds(-5.0));
+        assert_eq!(v[2], Duration::from_microseconds(1));
+        assert_eq!(v[3], v[4]);
+        v.dedup();
+        assert_eq!(v.len(), 4);
+        assert_eq!(v.iter().max(), Some(&Duration::from_hours(1.0)));
+
+        let mut counts = std::collections::HashMap::new();
+        for d in [60.0, 30.0, 60.0].map(Duration::from_seconds) {
+            *counts.entry(d).or_insert(0) += 1;
+        }
+        assert_eq!(counts[&Duration::from_minutes(1.0)], 2);
+        assert_eq!(counts.len(), 2);
+    }
+}
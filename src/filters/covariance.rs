@@ -0,0 +1,70 @@
+use crate::matrixutils::symmetric_eigen;
+use crate::{Matrix3, Vector3};
+use crate::{SCError, SCResult};
+
+/// Principal axes of the uncertainty ellipsoid of a 3x3 covariance
+///
+/// # Arguments
+/// * `p` - The symmetric covariance matrix
+/// * `nsigma` - Confidence scale, e.g. 1.0 for the 1-sigma ellipsoid
+///
+/// # Returns
+/// The semi-axis lengths in ascending order and a right-handed rotation
+/// matrix whose columns are the corresponding axis directions, or
+/// `SCError::NonPositiveDefiniteMatrix` if the covariance is not positive
+/// definite
+///
+/// # Example
+/// ```
+/// use satctrl::filters::covariance_ellipsoid;
+/// use satctrl::{Matrix3, Vector3};
+/// let p = Matrix3::diag_from_vector(&Vector3::from_vec([4.0, 9.0, 1.0]));
+/// let (axes, rot) = covariance_ellipsoid(&p, 1.0).unwrap();
+/// assert!((axes[2] - 3.0).abs() < 1.0e-12);
+/// ```
+///
+pub fn covariance_ellipsoid(p: &Matrix3, nsigma: f64) -> SCResult<(Vector3, Matrix3)> {
+    let (d, mut v) = symmetric_eigen(p)?;
+    if d[0] <= 0.0 {
+        return Err(SCError::NonPositiveDefiniteMatrix);
+    }
+    if v.determinant() < 0.0 {
+        for k in 0..3 {
+            v[(k, 2)] = -v[(k, 2)];
+        }
+    }
+    let mut axes = Vector3::zeros();
+    for i in 0..3 {
+        axes[i] = nsigma * d[i].sqrt();
+    }
+    Ok((axes, v))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_covariance_ellipsoid() {
+        let p = Matrix3::diag_from_vector(&Vector3::from_vec([4.0, 9.0, 1.0]));
+        let (axes, rot) = covariance_ellipsoid(&p, 1.0).unwrap();
+        // Ascending order: z, x, y standard deviations
+        for (i, (sigma, axis)) in [(1.0, 2), (2.0, 0), (3.0, 1)].iter().enumerate() {
+            assert!((axes[i] - sigma).abs() < 1.0e-12);
+            assert!((rot[(*axis, i)].abs() - 1.0).abs() < 1.0e-12);
+        }
+        assert!((rot.determinant() - 1.0).abs() < 1.0e-12);
+
+        let (axes3, _) = covariance_ellipsoid(&p, 3.0).unwrap();
+        assert!((axes3[2] - 9.0).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn test_covariance_ellipsoid_indefinite() {
+        let p = Matrix3::diag_from_vector(&Vector3::from_vec([4.0, -1.0, 1.0]));
+        assert!(matches!(
+            covariance_ellipsoid(&p, 1.0),
+            Err(SCError::NonPositiveDefiniteMatrix)
+        ));
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{SCError, SCResult};
+
+/// Exponential moving average of a scalar stream
+///
+/// Each update blends the new sample into the average,
+/// `avg = alpha * x + (1 - alpha) * avg`, so larger `alpha` tracks the
+/// input more quickly. The first sample initializes the average.
+///
+/// # Example
+/// ```
+/// use satctrl::filters::ema::ExponentialMovingAverage;
+/// let mut ema = ExponentialMovingAverage::new(0.5).unwrap();
+/// assert_eq!(ema.update(2.0), 2.0);
+/// assert_eq!(ema.update(4.0), 3.0);
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct ExponentialMovingAverage {
+    alpha: f64,
+    value: Option<f64>,
+}
+
+impl ExponentialMovingAverage {
+    /// Create a new exponential moving average
+    ///
+    /// # Arguments
+    /// * `alpha` - Smoothing factor, in range (0, 1]
+    ///
+    /// # Returns
+    /// The filter, or `SCError::InvalidInput` if `alpha` is out of range
+    ///
+    pub fn new(alpha: f64) -> SCResult<Self> {
+        if !(alpha > 0.0 && alpha <= 1.0) {
+            return Err(SCError::InvalidInput);
+        }
+        Ok(Self { alpha, value: None })
+    }
+
+    /// Add a sample and return the updated average
+    pub fn update(&mut self, x: f64) -> f64 {
+        let value = match self.value {
+            Some(v) => v + self.alpha * (x - v),
+            None => x,
+        };
+        self.value = Some(value);
+        value
+    }
+
+    /// The current average, or `None` before the first sample
+    pub fn value(&self) -> Option<f64> {
+        self.value
+    }
+
+    /// Discard all samples
+    pub fn reset(&mut self) {
+        self.value = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ema() {
+        let mut ema = ExponentialMovingAverage::new(0.1).unwrap();
+        assert_eq!(ema.value(), None);
+        ema.update(0.0);
+        for _ in 0..500 {
+            ema.update(3.0);
+        }
+        assert!((ema.value().unwrap() - 3.0).abs() < 1.0e-12);
+
+        ema.reset();
+        assert_eq!(ema.update(-1.0), -1.0);
+
+        assert!(matches!(
+            ExponentialMovingAverage::new(0.0),
+            Err(SCError::InvalidInput)
+        ));
+        assert!(matches!(
+            ExponentialMovingAverage::new(1.5),
+            Err(SCError::InvalidInput)
+        ));
+    }
+}
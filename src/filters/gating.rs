@@ -0,0 +1,123 @@
+use crate::{Matrix, Vector};
+use crate::{SCError, SCResult};
+
+/// Squared Mahalanobis distance of a measurement residual
+///
+/// # Arguments
+/// * `residual` - Measurement residual (innovation)
+/// * `s` - Residual (innovation) covariance
+///
+/// # Returns
+/// The squared distance `rᵀ S⁻¹ r`, or `SCError::MatrixIsSingular`
+/// if the covariance cannot be inverted
+///
+/// # Example
+/// ```
+/// use satctrl::filters::mahalanobis_sq;
+/// use satctrl::{Matrix2, Vector2};
+/// let r = Vector2::from_vec([1.0, 2.0]);
+/// let s = Matrix2::from_row_major_array([[4.0, 0.0], [0.0, 1.0]]);
+/// let d2 = mahalanobis_sq(&r, &s).unwrap();
+/// assert_eq!(d2, 4.25);
+/// ```
+///
+pub fn mahalanobis_sq<const M: usize>(residual: &Vector<M>, s: &Matrix<M, M>) -> SCResult<f64> {
+    let sinv = s.inverse().ok_or(SCError::MatrixIsSingular)?;
+    Ok(residual.dot(&(sinv * *residual)))
+}
+
+/// Normalized estimation error squared (NEES)
+///
+/// Averaged over Monte Carlo runs, the NEES of a consistent filter is
+/// chi-squared distributed with `N` degrees of freedom, so its mean
+/// should be close to `N`
+///
+/// # Arguments
+/// * `error` - Estimation error, estimate minus truth
+/// * `covariance` - Filter covariance of the estimate
+///
+/// # Returns
+/// The normalized error `eᵀ P⁻¹ e`, or `SCError::MatrixIsSingular` if
+/// the covariance cannot be inverted
+///
+/// # Example
+/// ```
+/// use satctrl::filters::nees;
+/// use satctrl::{Matrix2, Vector2};
+/// let e = Vector2::from_vec([0.5, -1.0]);
+/// let p = Matrix2::from_row_major_array([[0.25, 0.0], [0.0, 4.0]]);
+/// assert!((nees(&e, &p).unwrap() - 1.25).abs() < 1.0e-12);
+/// ```
+///
+pub fn nees<const N: usize>(error: &Vector<N>, covariance: &Matrix<N, N>) -> SCResult<f64> {
+    mahalanobis_sq(error, covariance)
+}
+
+/// Chi-squared gate on a measurement residual
+///
+/// # Arguments
+/// * `residual` - Measurement residual (innovation)
+/// * `s` - Residual (innovation) covariance
+/// * `threshold` - Gate on the squared Mahalanobis distance, typically a
+///   chi-squared quantile with `M` degrees of freedom
+///
+/// # Returns
+/// True if the measurement falls inside the gate and should be accepted
+///
+/// # Example
+/// ```
+/// use satctrl::filters::chi2_gate;
+/// use satctrl::{Matrix2, Vector2};
+/// let r = Vector2::from_vec([1.0, 2.0]);
+/// let s = Matrix2::identity();
+/// // 99% quantile of chi-squared with 2 degrees of freedom
+/// assert!(chi2_gate(&r, &s, 9.21).unwrap());
+/// ```
+///
+pub fn chi2_gate<const M: usize>(
+    residual: &Vector<M>,
+    s: &Matrix<M, M>,
+    threshold: f64,
+) -> SCResult<bool> {
+    Ok(mahalanobis_sq(residual, s)? <= threshold)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Matrix2, Vector2};
+
+    #[test]
+    fn test_mahalanobis() {
+        let r = Vector2::from_vec([1.0, -1.0]);
+        let s = Matrix2::from_row_major_array([[2.0, 1.0], [1.0, 2.0]]);
+        // S⁻¹ = [[2, -1], [-1, 2]] / 3, so rᵀ S⁻¹ r = (2 + 1 + 1 + 2) / 3
+        let d2 = mahalanobis_sq(&r, &s).unwrap();
+        assert!((d2 - 2.0).abs() < 1.0e-12);
+
+        assert!(chi2_gate(&r, &s, 2.5).unwrap());
+        assert!(!chi2_gate(&r, &s, 1.5).unwrap());
+    }
+
+    #[test]
+    fn test_nees() {
+        // P⁻¹ = [[3, -1], [-1, 1]] / 2, so eᵀ P⁻¹ e = (3 - 4 + 4) / 2
+        let e = Vector2::from_vec([1.0, 2.0]);
+        let p = Matrix2::from_row_major_array([[1.0, 1.0], [1.0, 3.0]]);
+        assert!((nees(&e, &p).unwrap() - 1.5).abs() < 1.0e-12);
+
+        let p = Matrix2::from_row_major_array([[1.0, 2.0], [2.0, 4.0]]);
+        assert!(matches!(nees(&e, &p), Err(SCError::MatrixIsSingular)));
+    }
+
+    #[test]
+    fn test_singular_covariance() {
+        let r = Vector2::from_vec([1.0, -1.0]);
+        let s = Matrix2::from_row_major_array([[1.0, 1.0], [1.0, 1.0]]);
+        assert!(matches!(
+            mahalanobis_sq(&r, &s),
+            Err(SCError::MatrixIsSingular)
+        ));
+        assert!(chi2_gate(&r, &s, 10.0).is_err());
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{Matrix, Vector};
+
+/// Numerical Jacobian by central differences
+///
+/// # Arguments
+/// * `f` - The function to differentiate
+/// * `x` - The point at which to evaluate the Jacobian
+/// * `eps` - The perturbation applied to each state, on both sides
+///
+/// # Returns
+/// The M x N Jacobian `∂f/∂x`, with truncation error O(eps²)
+///
+/// # Notes
+/// * Each column costs two evaluations of `f`
+/// * For states of very different scales choose `eps` for the smallest
+///   scale, or non-dimensionalize the states first
+///
+/// # Example
+/// ```
+/// use satctrl::filters::numerical_jacobian;
+/// use satctrl::Vector2;
+/// let f = |x: &Vector2| Vector2::from_vec([x[0] * x[1], x[0].sin()]);
+/// let j = numerical_jacobian(f, &Vector2::from_vec([1.0, 2.0]), 1.0e-6);
+/// assert!((j[(0, 0)] - 2.0).abs() < 1.0e-8);
+/// ```
+///
+pub fn numerical_jacobian<const N: usize, const M: usize>(
+    f: impl Fn(&Vector<N>) -> Vector<M>,
+    x: &Vector<N>,
+    eps: f64,
+) -> Matrix<M, N> {
+    let mut jac = Matrix::<M, N>::zeros();
+    for j in 0..N {
+        let mut xp = *x;
+        let mut xm = *x;
+        xp[j] += eps;
+        xm[j] -= eps;
+        let df = (f(&xp) - f(&xm)) / (2.0 * eps);
+        for i in 0..M {
+            jac[(i, j)] = df[i];
+        }
+    }
+    jac
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vector3;
+
+    #[test]
+    fn test_numerical_jacobian() {
+        // Range and bearing from the origin to a point in the plane, plus
+        // a nonlinear third state
+        let f = |x: &Vector3| {
+            Vector::<3>::from_vec([x[0].hypot(x[1]), x[1].atan2(x[0]), x[0] * x[2].exp()])
+        };
+        let x = Vector3::from_vec([3.0, 4.0, -0.5]);
+        let r = 5.0;
+        let analytic = Matrix::<3, 3>::from_row_major_array([
+            [x[0] / r, x[1] / r, 0.0],
+            [-x[1] / (r * r), x[0] / (r * r), 0.0],
+            [x[2].exp(), 0.0, x[0] * x[2].exp()],
+        ]);
+        let numeric = numerical_jacobian(f, &x, 1.0e-5);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((numeric[(i, j)] - analytic[(i, j)]).abs() < 1.0e-9);
+            }
+        }
+
+        // Non-square: 3 states to 2 measurements
+        let g = |x: &Vector3| Vector::<2>::from_vec([x[0] + 2.0 * x[2], x[1] * x[1]]);
+        let jg = numerical_jacobian(g, &x, 1.0e-4);
+        assert!((jg[(0, 2)] - 2.0).abs() < 1.0e-9);
+        assert!((jg[(1, 1)] - 8.0).abs() < 1.0e-9);
+        assert!(jg[(1, 0)].abs() < 1.0e-12);
+    }
+}
@@ -1,3 +1,18 @@
+/// Exponential moving average of scalar streams
+pub mod ema;
+/// Discrete-time process noise
+pub mod process_noise;
+
+mod covariance;
+mod gating;
+mod jacobian;
+mod moving_average;
 mod ukf;
+mod wls;
 
+pub use covariance::covariance_ellipsoid;
+pub use gating::{chi2_gate, mahalanobis_sq, nees};
+pub use jacobian::numerical_jacobian;
+pub use moving_average::MovingAverage;
 pub use ukf::UKF;
+pub use wls::wls;
@@ -0,0 +1,94 @@
+/// Windowed mean of the last `W` samples of a scalar stream
+///
+/// Samples are held in a fixed-size ring buffer. Until `W` samples have
+/// been added, the mean is over the samples seen so far.
+///
+/// # Example
+/// ```
+/// use satctrl::filters::MovingAverage;
+/// let mut ma = MovingAverage::<3>::new();
+/// ma.update(1.0);
+/// ma.update(2.0);
+/// ma.update(3.0);
+/// assert_eq!(ma.update(7.0), 4.0);
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct MovingAverage<const W: usize> {
+    buffer: [f64; W],
+    next: usize,
+    count: usize,
+}
+
+impl<const W: usize> Default for MovingAverage<W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const W: usize> MovingAverage<W> {
+    /// Create an empty moving average; the window `W` must be non-zero
+    pub fn new() -> Self {
+        const { assert!(W > 0, "moving average window must be non-zero") };
+        Self {
+            buffer: [0.0; W],
+            next: 0,
+            count: 0,
+        }
+    }
+
+    /// Add a sample, replacing the oldest once the window is full, and
+    /// return the updated mean
+    pub fn update(&mut self, x: f64) -> f64 {
+        self.buffer[self.next] = x;
+        self.next = (self.next + 1) % W;
+        self.count = (self.count + 1).min(W);
+        self.mean()
+    }
+
+    /// The mean of the samples in the window, or NaN if there are none
+    pub fn mean(&self) -> f64 {
+        // Summed afresh so that round-off does not accumulate
+        self.buffer[..self.count].iter().sum::<f64>() / self.count as f64
+    }
+
+    /// Number of samples in the window
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// True if no samples have been added
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Discard all samples
+    pub fn reset(&mut self) {
+        self.next = 0;
+        self.count = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_moving_average() {
+        let mut ma = MovingAverage::<4>::new();
+        assert!(ma.is_empty());
+        assert!(ma.mean().is_nan());
+        assert_eq!(ma.update(2.0), 2.0);
+        assert_eq!(ma.update(4.0), 3.0);
+        ma.update(6.0);
+        ma.update(8.0);
+        // Window now holds [4, 6, 8, 10]
+        assert_eq!(ma.update(10.0), 7.0);
+        assert_eq!(ma.len(), 4);
+        assert_eq!(ma.update(-8.0), 4.0);
+
+        ma.reset();
+        assert!(ma.is_empty());
+        assert_eq!(ma.update(5.0), 5.0);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{Matrix, Matrix2};
+
+/// Process noise for a position-velocity random walk
+///
+/// Discrete process noise of a 1-D position & velocity state driven
+/// by white acceleration noise (a.k.a. the constant-velocity model)
+///
+/// # Arguments
+/// * `q` - Power spectral density of the acceleration noise, units²/s³
+/// * `dt` - The time step, seconds
+///
+/// # Returns
+/// The 2x2 discrete process-noise covariance for the (position, velocity) state
+///
+/// # Example
+/// ```
+/// use satctrl::filters::process_noise::random_walk;
+/// let q = random_walk(1.0e-3, 0.1);
+/// ```
+///
+pub fn random_walk(q: f64, dt: f64) -> Matrix2 {
+    let dt2 = dt * dt;
+    let dt3 = dt2 * dt;
+    Matrix2::from_row_major_array([[dt3 / 3.0, dt2 / 2.0], [dt2 / 2.0, dt]]) * q
+}
+
+/// Process noise for a first-order Gauss-Markov process
+///
+/// The process `x[k+1] = exp(-dt/tau) * x[k] + w[k]` has steady-state
+/// standard deviation `sigma` when `w[k]` has the returned variance
+///
+/// # Arguments
+/// * `sigma` - Steady-state standard deviation of the process
+/// * `tau` - Correlation time, seconds
+/// * `dt` - The time step, seconds
+///
+/// # Returns
+/// The variance of the discrete driving noise `w[k]`
+///
+/// # Example
+/// ```
+/// use satctrl::filters::process_noise::gauss_markov;
+/// let q = gauss_markov(1.0, 100.0, 1.0);
+/// ```
+///
+pub fn gauss_markov(sigma: f64, tau: f64, dt: f64) -> f64 {
+    sigma * sigma * (1.0 - (-2.0 * dt / tau).exp())
+}
+
+/// Discretize a continuous-time linear system with Van Loan's method
+///
+/// For `dx/dt = F x + w`, where `w` is white noise with power spectral
+/// density `Q`, compute the state transition matrix `Φ = exp(F dt)`
+/// and the discrete process noise `Qd = ∫ Φ(s) Q Φ(s)ᵀ ds` over the step
+///
+/// # Arguments
+/// * `f` - The continuous-time system matrix
+/// * `q` - The continuous-time process noise power spectral density
+/// * `dt` - The time step
+///
+/// # Returns
+/// A tuple of the state transition matrix and the discrete process noise
+///
+/// # Notes
+/// * Reference: C. F. Van Loan, "Computing Integrals Involving the
+///   Matrix Exponential", IEEE Trans. Automatic Control, 1978
+///
+/// # Example
+/// ```
+/// use satctrl::filters::process_noise::discretize;
+/// use satctrl::Matrix2;
+/// let f = Matrix2::from_row_major_array([[0.0, 1.0], [0.0, 0.0]]);
+/// let q = Matrix2::from_row_major_array([[0.0, 0.0], [0.0, 1.0e-3]]);
+/// let (phi, qd) = discretize(&f, &q, 0.1);
+/// ```
+///
+pub fn discretize<const N: usize>(
+    f: &Matrix<N, N>,
+    q: &Matrix<N, N>,
+    dt: f64,
+) -> (Matrix<N, N>, Matrix<N, N>) {
+    // exp([[-F, Q], [0, Fᵀ]] dt) = [[.., Φ⁻¹ Qd], [0, Φᵀ]]
+    let (e12, e22) = block_expm(&(*f * -dt), &(*q * dt), &(f.transpose() * dt));
+    let phi = e22.transpose();
+    (phi, phi * e12)
+}
+
+/// Exponential of the block upper-triangular matrix [[A, B], [0, C]]
+///
+/// Uses a Taylor series with scaling and squaring, working on the
+/// blocks so that no 2N x 2N matrix is needed
+///
+/// # Returns
+/// The upper-right and lower-right blocks of the exponential
+fn block_expm<const N: usize>(
+    a: &Matrix<N, N>,
+    b: &Matrix<N, N>,
+    c: &Matrix<N, N>,
+) -> (Matrix<N, N>, Matrix<N, N>) {
+    let mut norm = 0.0;
+    for i in 0..N {
+        let mut rowsum = 0.0;
+        for j in 0..N {
+            rowsum += a[(i, j)].abs() + b[(i, j)].abs() + c[(i, j)].abs();
+        }
+        norm = f64::max(norm, rowsum);
+    }
+
+    // Scale so the series converges quickly
+    let mut squarings = 0;
+    while norm > 0.5 {
+        norm /= 2.0;
+        squarings += 1;
+    }
+    let scale = 0.5_f64.powi(squarings);
+    let (a, b, c) = (*a * scale, *b * scale, *c * scale);
+
+    // Top-right block of M^k obeys X[k+1] = A X[k] + B C^k
+    let mut ak = Matrix::<N, N>::identity();
+    let mut xk = Matrix::<N, N>::zeros();
+    let mut ck = Matrix::<N, N>::identity();
+    let mut e11 = Matrix::<N, N>::identity();
+    let mut e12 = Matrix::<N, N>::zeros();
+    let mut e22 = Matrix::<N, N>::identity();
+    let mut factorial = 1.0;
+    for k in 1..20 {
+        factorial *= k as f64;
+        xk = a * xk + b * ck;
+        ak = a * ak;
+        ck = c * ck;
+        e11 += ak / factorial;
+        e12 += xk / factorial;
+        e22 += ck / factorial;
+    }
+
+    for _ in 0..squarings {
+        e12 = e11 * e12 + e12 * e22;
+        e11 = e11 * e11;
+        e22 = e22 * e22;
+    }
+    (e12, e22)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_van_loan() {
+        let f = Matrix2::from_row_major_array([[0.0, 1.0], [0.0, 0.0]]);
+        let q = Matrix2::from_row_major_array([[0.0, 0.0], [0.0, 2.0e-3]]);
+        let dt = 3.0;
+        let (phi, qd) = discretize(&f, &q, dt);
+        let qd_expected = random_walk(2.0e-3, dt);
+        let phi_expected = Matrix2::from_row_major_array([[1.0, dt], [0.0, 1.0]]);
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((phi[(i, j)] - phi_expected[(i, j)]).abs() < 1.0e-12);
+                assert!((qd[(i, j)] - qd_expected[(i, j)]).abs() < 1.0e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gauss_markov() {
+        // Scalar Gauss-Markov process through Van Loan matches the closed form
+        let (sigma, tau, dt) = (2.0, 50.0, 10.0);
+        let f = Matrix::<1, 1>::from_row_major_array([[-1.0 / tau]]);
+        let q = Matrix::<1, 1>::from_row_major_array([[2.0 * sigma * sigma / tau]]);
+        let (phi, qd) = discretize(&f, &q, dt);
+        assert!((phi[(0, 0)] - (-dt / tau).exp()).abs() < 1.0e-12);
+        assert!((qd[(0, 0)] - gauss_markov(sigma, tau, dt)).abs() < 1.0e-12);
+
+        // Long time steps decorrelate completely
+        assert!((gauss_markov(sigma, tau, 1.0e6) - sigma * sigma).abs() < 1.0e-12);
+    }
+}
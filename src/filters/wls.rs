@@ -0,0 +1,94 @@
+use crate::{Matrix, Vector};
+use crate::{SCError, SCResult};
+
+/// Weighted least-squares estimate from a batch of linear measurements
+///
+/// Solves the normal equations for the measurement model `z = H x + v`,
+/// where the weight `W` is typically the inverse measurement covariance
+///
+/// # Arguments
+/// * `h` - The M x N measurement matrix
+/// * `z` - The M measurements
+/// * `w` - The M x M symmetric positive definite weight matrix
+///
+/// # Returns
+/// The estimate `(HᵀWH)⁻¹HᵀWz` and its covariance `(HᵀWH)⁻¹`, or
+/// `SCError::MatrixIsSingular` if the normal matrix cannot be inverted
+/// (e.g. the states are not all observable from the measurements)
+///
+/// # Example
+/// ```
+/// use satctrl::filters::wls;
+/// use satctrl::{Matrix, Vector};
+/// // Fit z = a + b t to three points on the line z = 1 + 2 t
+/// let h = Matrix::<3, 2>::from_row_major_array([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]]);
+/// let z = Vector::<3>::from_vec([1.0, 3.0, 5.0]);
+/// let (x, _p) = wls(&h, &z, &Matrix::<3, 3>::identity()).unwrap();
+/// assert!((x[0] - 1.0).abs() < 1.0e-12 && (x[1] - 2.0).abs() < 1.0e-12);
+/// ```
+///
+pub fn wls<const M: usize, const N: usize>(
+    h: &Matrix<M, N>,
+    z: &Vector<M>,
+    w: &Matrix<M, M>,
+) -> SCResult<(Vector<N>, Matrix<N, N>)> {
+    let htw = h.transpose() * *w;
+    let p = (htw * *h).inverse().ok_or(SCError::MatrixIsSingular)?;
+    Ok((p * (htw * *z), p))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wls_fit() {
+        // Noisy samples of z = 0.5 - 1.5 t + 0.25 t^2. The noise is a
+        // 4th difference, orthogonal to all quadratics, so the least-squares
+        // fit recovers the coefficients exactly
+        let t = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let noise = [0.05, -0.2, 0.3, -0.2, 0.05];
+        let mut h = Matrix::<5, 3>::zeros();
+        let mut z = Vector::<5>::zeros();
+        for i in 0..5 {
+            h[(i, 0)] = 1.0;
+            h[(i, 1)] = t[i];
+            h[(i, 2)] = t[i] * t[i];
+            z[i] = 0.5 - 1.5 * t[i] + 0.25 * t[i] * t[i] + noise[i];
+        }
+        let (x, p) = wls(&h, &z, &Matrix::<5, 5>::identity()).unwrap();
+        assert!((x[0] - 0.5).abs() < 1.0e-12);
+        assert!((x[1] + 1.5).abs() < 1.0e-12);
+        assert!((x[2] - 0.25).abs() < 1.0e-12);
+        let pinv = h.transpose() * h;
+        let eye = p * pinv;
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((eye[(i, j)] - expected).abs() < 1.0e-10);
+            }
+        }
+
+        // Down-weighting a corrupted measurement recovers the fit
+        let mut zbad = z;
+        for i in 0..5 {
+            zbad[i] -= noise[i];
+        }
+        zbad[4] += 10.0;
+        let mut w = Matrix::<5, 5>::identity();
+        w[(4, 4)] = 1.0e-12;
+        let (x, _) = wls(&h, &zbad, &w).unwrap();
+        assert!((x[1] + 1.5).abs() < 1.0e-8);
+    }
+
+    #[test]
+    fn test_wls_singular() {
+        // Two identical columns: the states cannot be separated
+        let h = Matrix::<3, 2>::from_row_major_array([[1.0, 1.0], [2.0, 2.0], [3.0, 3.0]]);
+        let z = Vector::<3>::from_vec([1.0, 2.0, 3.0]);
+        assert!(matches!(
+            wls(&h, &z, &Matrix::<3, 3>::identity()),
+            Err(SCError::MatrixIsSingular)
+        ));
+    }
+}
@@ -0,0 +1,140 @@
+use crate::constants::R_EARTH_WGS84;
+use crate::Vector3;
+
+/// Exponential atmosphere: base altitude (km), base density (kg/m^3),
+/// and scale height (km) of each band
+///
+/// From Vallado, "Fundamentals of Astrodynamics and Applications",
+/// Table 8-4 (CIRA-72 below 25 km from the U.S. Standard Atmosphere)
+///
+#[rustfmt::skip]
+const DENSITY_TABLE: [(f64, f64, f64); 28] = [
+    (0.0,    1.225,     7.249),
+    (25.0,   3.899e-2,  6.349),
+    (30.0,   1.774e-2,  6.682),
+    (40.0,   3.972e-3,  7.554),
+    (50.0,   1.057e-3,  8.382),
+    (60.0,   3.206e-4,  7.714),
+    (70.0,   8.770e-5,  6.549),
+    (80.0,   1.905e-5,  5.799),
+    (90.0,   3.396e-6,  5.382),
+    (100.0,  5.297e-7,  5.877),
+    (110.0,  9.661e-8,  7.263),
+    (120.0,  2.438e-8,  9.473),
+    (130.0,  8.484e-9,  12.636),
+    (140.0,  3.845e-9,  16.149),
+    (150.0,  2.070e-9,  22.523),
+    (180.0,  5.464e-10, 29.740),
+    (200.0,  2.789e-10, 37.105),
+    (250.0,  7.248e-11, 45.546),
+    (300.0,  2.418e-11, 53.628),
+    (350.0,  9.518e-12, 53.298),
+    (400.0,  3.725e-12, 58.515),
+    (450.0,  1.585e-12, 60.828),
+    (500.0,  6.967e-13, 63.822),
+    (600.0,  1.454e-13, 71.835),
+    (700.0,  3.614e-14, 88.667),
+    (800.0,  1.170e-14, 124.64),
+    (900.0,  5.245e-15, 181.05),
+    (1000.0, 3.019e-15, 268.00),
+];
+
+/// Atmospheric density from a piecewise exponential model
+///
+/// # Arguments
+/// * `altitude` - Height above the Earth's equatorial radius, meters
+///
+/// # Returns
+/// Density in kg/m^3. Altitudes below zero use the sea-level band and
+/// altitudes above 1000 km extrapolate the top band
+///
+/// # Example
+/// ```
+/// use satctrl::forcemodels::density_exponential;
+/// let rho = density_exponential(400.0e3);
+/// assert!((rho - 3.725e-12).abs() < 1.0e-15);
+/// ```
+///
+pub fn density_exponential(altitude: f64) -> f64 {
+    let h = altitude * 1.0e-3;
+    let (h0, rho0, scale) = DENSITY_TABLE
+        .iter()
+        .rev()
+        .find(|(h0, _, _)| h >= *h0)
+        .unwrap_or(&DENSITY_TABLE[0]);
+    rho0 * (-(h - h0) / scale).exp()
+}
+
+/// Acceleration due to atmospheric drag
+///
+/// a = -½ ρ (C_d A / m) |v_rel| v_rel, where the atmosphere co-rotates with
+/// the Earth so that v_rel = v - ω × r. Density is from
+/// `density_exponential` at the height above a spherical Earth
+///
+/// # Arguments
+/// * `r` - Position in an Earth-centered inertial frame, meters
+/// * `v` - Velocity in the same frame, meters / second
+/// * `cd_a_over_m` - Drag coefficient times area over mass, m^2/kg
+/// * `omega_earth` - Earth rotation rate about the z axis, rad/s
+///
+/// # Returns
+/// The drag acceleration in the inertial frame, m/s^2
+///
+/// # Example
+/// ```
+/// use satctrl::forcemodels::drag_acceleration;
+/// use satctrl::constants::OMEGA_EARTH;
+/// use satctrl::Vector3;
+/// let r = Vector3::from_vec([6778.0e3, 0.0, 0.0]);
+/// let v = Vector3::from_vec([0.0, 7668.0, 0.0]);
+/// let a = drag_acceleration(&r, &v, 2.2 * 0.01, OMEGA_EARTH);
+/// assert!(a[1] < 0.0);
+/// ```
+///
+pub fn drag_acceleration(r: &Vector3, v: &Vector3, cd_a_over_m: f64, omega_earth: f64) -> Vector3 {
+    let omega = Vector3::from_vec([0.0, 0.0, omega_earth]);
+    let vrel = *v - omega.cross(r);
+    let rho = density_exponential(r.norm() - R_EARTH_WGS84);
+    vrel * (-0.5 * rho * cd_a_over_m * vrel.norm())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::OMEGA_EARTH;
+
+    #[test]
+    fn test_density_exponential() {
+        assert_eq!(density_exponential(0.0), 1.225);
+        assert!(density_exponential(-100.0) > 1.225);
+        // Close to continuous across band boundaries
+        for (h0, rho0, _) in DENSITY_TABLE.iter().skip(1) {
+            let below = density_exponential(h0 * 1.0e3 - 1.0e-6);
+            assert!((below - rho0).abs() < 0.1 * rho0);
+        }
+        // Decreasing with altitude
+        let mut prev = f64::INFINITY;
+        for h in (0..1200).map(|km| km as f64 * 1.0e3) {
+            let rho = density_exponential(h);
+            assert!(rho < prev);
+            prev = rho;
+        }
+    }
+
+    #[test]
+    fn test_drag_acceleration() {
+        let r = Vector3::from_vec([4000.0e3, 3000.0e3, 4500.0e3]);
+        let v = Vector3::from_vec([-5000.0, 5500.0, 1000.0]);
+        let a = drag_acceleration(&r, &v, 0.02, OMEGA_EARTH);
+        let vrel = v - Vector3::from_vec([0.0, 0.0, OMEGA_EARTH]).cross(&r);
+        // Opposes the velocity relative to the atmosphere
+        assert!(a.cross(&vrel).norm() < 1.0e-12 * a.norm() * vrel.norm());
+        assert!(a.dot(&vrel) < 0.0);
+        let rho = density_exponential(r.norm() - R_EARTH_WGS84);
+        assert!((a.norm() - 0.5 * rho * 0.02 * vrel.normsq()).abs() < 1.0e-12 * a.norm());
+
+        // No drag without relative motion
+        let v = Vector3::from_vec([0.0, 0.0, OMEGA_EARTH]).cross(&r);
+        assert_eq!(drag_acceleration(&r, &v, 0.02, OMEGA_EARTH).norm(), 0.0);
+    }
+}
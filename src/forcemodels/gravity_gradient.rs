@@ -0,0 +1,61 @@
+use crate::{Matrix3, Vector3};
+
+/// Gravity-gradient torque on a rigid body
+///
+/// τ = 3 μ / R⁵ · (r × I·r)
+///
+/// # Arguments
+/// * `r_body` - Vector between the central body and the spacecraft,
+///   expressed in body coordinates, meters. The torque is unchanged by its
+///   sign, so the nadir vector may be used directly
+/// * `inertia` - Spacecraft inertia tensor in body coordinates, kg·m²
+/// * `mu` - Gravitational parameter of the central body, m^3/s^2
+///
+/// # Returns
+/// The torque in body coordinates, N·m. Zero when the nadir vector lies
+/// along a principal axis of inertia
+///
+/// # Example
+/// ```
+/// use satctrl::forcemodels::gravity_gradient_torque;
+/// use satctrl::constants::MU_EARTH;
+/// use satctrl::{Matrix3, Vector3};
+/// let inertia = Matrix3::diag_from_vector(&Vector3::from_vec([10.0, 12.0, 8.0]));
+/// let r = Vector3::from_vec([0.0, 6.0e6, 3.0e6]);
+/// let torque = gravity_gradient_torque(&r, &inertia, MU_EARTH);
+/// ```
+///
+pub fn gravity_gradient_torque(r_body: &Vector3, inertia: &Matrix3, mu: f64) -> Vector3 {
+    let rnorm = r_body.norm();
+    r_body.cross(&(*inertia * *r_body)) * (3.0 * mu / rnorm.powi(5))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::MU_EARTH;
+
+    #[test]
+    fn test_gravity_gradient_torque() {
+        let inertia = Matrix3::diag_from_vector(&Vector3::from_vec([10.0, 12.0, 8.0]));
+        let rmag = 7.0e6;
+
+        // Nadir along a principal axis
+        for axis in [Vector3::xhat(), Vector3::yhat(), Vector3::zhat()] {
+            let torque = gravity_gradient_torque(&(axis * -rmag), &inertia, MU_EARTH);
+            assert_eq!(torque.norm(), 0.0);
+        }
+
+        // Pitched 45° about x: τ_x = 3 μ / R³ (I_z - I_y) r̂_y r̂_z
+        let s = 0.5_f64.sqrt();
+        let r = Vector3::from_vec([0.0, s, s]) * rmag;
+        let torque = gravity_gradient_torque(&r, &inertia, MU_EARTH);
+        let expected = 3.0 * MU_EARTH / rmag.powi(3) * (8.0 - 12.0) * 0.5;
+        assert!((torque[0] - expected).abs() < 1.0e-12 * expected.abs());
+        assert!(torque[1].abs() < 1.0e-20 && torque[2].abs() < 1.0e-20);
+        assert_eq!(
+            gravity_gradient_torque(&(r * -1.0), &inertia, MU_EARTH),
+            torque
+        );
+    }
+}
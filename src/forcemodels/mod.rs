@@ -0,0 +1,7 @@
+mod drag;
+mod gravity_gradient;
+mod srp;
+
+pub use drag::{density_exponential, drag_acceleration};
+pub use gravity_gradient::gravity_gradient_torque;
+pub use srp::srp_acceleration;
@@ -0,0 +1,66 @@
+use crate::constants::{AU, C_LIGHT, SOLAR_IRRADIANCE};
+use crate::Vector3;
+
+/// Acceleration due to solar radiation pressure
+///
+/// Cannonball model: the acceleration points directly away from the Sun,
+/// with the radiation pressure scaled by the inverse square of the
+/// distance from the Sun
+///
+/// # Arguments
+/// * `r_sat` - Satellite position, meters
+/// * `r_sun` - Sun position in the same frame, meters
+/// * `cr_a_over_m` - Reflectivity coefficient times area over mass, m^2/kg
+/// * `shadow` - Fraction of the solar disk visible from the satellite,
+///   from 1 in full sunlight to 0 in umbra
+///
+/// # Returns
+/// The radiation-pressure acceleration, m/s^2
+///
+/// # Example
+/// ```
+/// use satctrl::forcemodels::srp_acceleration;
+/// use satctrl::constants::AU;
+/// use satctrl::Vector3;
+/// let r_sat = Vector3::from_vec([42164.0e3, 0.0, 0.0]);
+/// let r_sun = Vector3::from_vec([AU, 0.0, 0.0]);
+/// let a = srp_acceleration(&r_sat, &r_sun, 1.5 * 0.02, 1.0);
+/// assert!(a[0] < 0.0);
+/// ```
+///
+pub fn srp_acceleration(
+    r_sat: &Vector3,
+    r_sun: &Vector3,
+    cr_a_over_m: f64,
+    shadow: f64,
+) -> Vector3 {
+    let d = *r_sat - *r_sun;
+    let dnorm = d.norm();
+    let pressure = SOLAR_IRRADIANCE / C_LIGHT * (AU / dnorm).powi(2);
+    d * (shadow * pressure * cr_a_over_m / dnorm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_srp_acceleration() {
+        let r_sat = Vector3::from_vec([30000.0e3, 25000.0e3, 1000.0e3]);
+        let r_sun = Vector3::from_vec([0.6 * AU, -0.8 * AU, 0.05 * AU]);
+        let a = srp_acceleration(&r_sat, &r_sun, 0.03, 1.0);
+
+        // Away from the Sun
+        let away = r_sat - r_sun;
+        assert!(a.cross(&away).norm() < 1.0e-12 * a.norm() * away.norm());
+        assert!(a.dot(&away) > 0.0);
+        // About 4.5e-6 N/m^2 near 1 AU
+        assert!((a.norm() / 0.03 - 4.54e-6).abs() < 0.05e-6);
+
+        // Linear in the shadow fraction
+        for shadow in [0.0, 0.25, 0.5] {
+            let ap = srp_acceleration(&r_sat, &r_sun, 0.03, shadow);
+            assert!((ap - a * shadow).norm() < 1.0e-15 * a.norm());
+        }
+    }
+}
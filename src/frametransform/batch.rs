@@ -0,0 +1,108 @@
+use crate::{Instant, Quaternion, Vector3};
+use std::collections::HashMap;
+
+/// Rotate many vectors by the same quaternion
+///
+/// # Arguments
+/// * `q` - The rotation to apply
+/// * `vecs` - The vectors to rotate
+///
+/// # Returns
+/// The rotated vectors, in the same order
+///
+/// # Notes
+/// * The quaternion is converted to a rotation matrix once, which is
+///   cheaper than quaternion rotation for more than a few vectors
+///
+/// # Example
+/// ```
+/// use satctrl::frametransform::transform_batch;
+/// use satctrl::{Quaternion, Vector3};
+/// let q = Quaternion::rotz(std::f64::consts::FRAC_PI_2);
+/// let out = transform_batch(&q, &[Vector3::xhat(), Vector3::yhat()]);
+/// assert!((out[0] - Vector3::yhat()).norm() < 1.0e-15);
+/// ```
+///
+pub fn transform_batch(q: &Quaternion, vecs: &[Vector3]) -> Vec<Vector3> {
+    let dcm = q.as_dcm();
+    vecs.iter().map(|v| dcm * *v).collect()
+}
+
+/// Rotate a time-tagged sequence of vectors into another frame
+///
+/// The rotation is computed once per distinct epoch, so vectors sharing
+/// an epoch (e.g. position and velocity, or several objects) reuse it
+///
+/// # Arguments
+/// * `states` - The epoch and vector of each element
+/// * `to_frame` - The rotation into the target frame at an epoch, e.g.
+///   `qteme2gcrf`
+///
+/// # Returns
+/// The rotated vectors, in the same order as `states`
+///
+/// # Example
+/// ```
+/// use satctrl::frametransform::{qteme2gcrf, transform_trajectory};
+/// use satctrl::{Duration, Instant, Vector3};
+/// let t0 = Instant::from_gregorian(2024, 1, 1, 0, 0, 0.0).unwrap();
+/// let t1 = t0 + Duration::from_seconds(60.0);
+/// let states = [(t0, Vector3::xhat()), (t0, Vector3::yhat()), (t1, Vector3::xhat())];
+/// let gcrf = transform_trajectory(&states, qteme2gcrf);
+/// assert_eq!(gcrf.len(), 3);
+/// ```
+///
+pub fn transform_trajectory<F>(states: &[(Instant, Vector3)], to_frame: F) -> Vec<Vector3>
+where
+    F: Fn(&Instant) -> Quaternion,
+{
+    let mut cache = HashMap::new();
+    states
+        .iter()
+        .map(|(tm, v)| *cache.entry(tm.raw).or_insert_with(|| to_frame(tm).as_dcm()) * *v)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frametransform::qteme2gcrf;
+    use crate::Duration;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_transform_batch() {
+        let q = Quaternion::from_rpy(0.3, -1.2, 2.5);
+        let vecs: Vec<Vector3> = (0..20)
+            .map(|i| Vector3::from_vec([i as f64, 1.0 - i as f64, 0.5 * i as f64]))
+            .collect();
+        let out = transform_batch(&q, &vecs);
+        assert_eq!(out.len(), vecs.len());
+        for (o, v) in out.iter().zip(vecs.iter()) {
+            assert!((*o - q * *v).norm() < 1.0e-13 * v.norm().max(1.0));
+        }
+        assert!(transform_batch(&q, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_transform_trajectory() {
+        let t0 = Instant::from_gregorian(2024, 1, 1, 0, 0, 0.0).unwrap();
+        let calls = Cell::new(0);
+        let to_frame = |tm: &Instant| {
+            calls.set(calls.get() + 1);
+            qteme2gcrf(tm)
+        };
+        // Three epochs, revisited out of order
+        let states: Vec<(Instant, Vector3)> = (0..12)
+            .map(|i| {
+                let tm = t0 + Duration::from_days((i % 3) as f64 * 100.0);
+                (tm, Vector3::from_vec([7.0e6, 1.0e3 * i as f64, -2.0e5]))
+            })
+            .collect();
+        let out = transform_trajectory(&states, to_frame);
+        assert_eq!(calls.get(), 3);
+        for ((tm, v), o) in states.iter().zip(out.iter()) {
+            assert!((*o - qteme2gcrf(tm) * *v).norm() < 1.0e-7);
+        }
+    }
+}
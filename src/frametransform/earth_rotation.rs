@@ -0,0 +1,237 @@
+use super::fk5::{centuries_tt, eqeq, ARCSEC2RAD};
+use crate::wrap_2pi;
+use crate::Instant;
+
+/// Earth Rotation Angle
+///
+/// The angle between the Celestial Intermediate Origin and the
+/// Terrestrial Intermediate Origin, as defined by IAU 2000 resolution B1.8
+///
+/// # Arguments
+/// * `tm` - The instant at which to compute the angle
+///
+/// # Returns
+/// The Earth Rotation Angle in radians, in range [0, 2π)
+///
+/// # Notes
+/// * UT1 is approximated as UTC (|UT1 - UTC| < 0.9 s)
+/// * For a reference, see IERS Conventions (2010), Eq. 5.15
+///
+/// # Example
+/// ```
+/// use satctrl::Instant;
+/// use satctrl::frametransform::era;
+/// let theta = era(&Instant::from_unixtime(946728000.0));
+/// ```
+///
+pub fn era(tm: &Instant) -> f64 {
+    let tu = tm.as_jd_utc() - 2451545.0;
+    // Split off the integer days to preserve precision
+    let frac = tu.rem_euclid(1.0);
+    let rev = frac + 0.7790572732640 + 0.00273781191135448 * tu;
+    wrap_2pi(std::f64::consts::TAU * rev.rem_euclid(1.0))
+}
+
+/// Greenwich Apparent Sidereal Time from the Earth Rotation Angle
+///
+/// The CIO-based form of IAU 2000: GAST = ERA - EO, where the equation
+/// of the origins EO accumulates precession and nutation in right
+/// ascension since J2000
+///
+/// # Arguments
+/// * `tm` - The instant at which to compute GAST
+///
+/// # Returns
+/// GAST in radians, in range [0, 2π)
+///
+/// # Notes
+/// * EO is the IAU 2000 polynomial (IERS Conventions 2003, Eq. 5.14)
+///   less the equation of the equinoxes `eqeq`, which uses the IAU 1980
+///   nutation in place of IAU 2000A; this changes GAST by a few
+///   milliarcseconds
+/// * Differs from the equinox-based GAST (IAU 1982 GMST plus `eqeq`)
+///   mainly by the IAU 2000 correction to the precession rate, about
+///   -0.275 arcsec per century since J2000
+/// * UT1 is approximated as UTC (|UT1 - UTC| < 0.9 s)
+///
+/// # Example
+/// ```
+/// use satctrl::Instant;
+/// use satctrl::frametransform::gast_2000;
+/// let theta = gast_2000(&Instant::from_unixtime(946728000.0));
+/// ```
+///
+pub fn gast_2000(tm: &Instant) -> f64 {
+    let t = centuries_tt(tm);
+    let eo = -(0.014506
+        + t * (4612.15739966 + t * (1.39667721 + t * (-0.00009344 + t * 0.00001882))))
+        * ARCSEC2RAD
+        - eqeq(tm);
+    wrap_2pi(era(tm) - eo)
+}
+
+/// Greenwich Mean Sidereal Time
+///
+/// # Arguments
+/// * `tm` - The instant at which to compute GMST
+///
+/// # Returns
+/// GMST in radians, in range [0, 2π)
+///
+/// # Notes
+/// * Uses the IAU 1982 model (Vallado, "Fundamentals of Astrodynamics
+///   and Applications", Eq. 3-47)
+/// * UT1 is approximated as UTC (|UT1 - UTC| < 0.9 s)
+///
+/// # Example
+/// ```
+/// use satctrl::Instant;
+/// use satctrl::frametransform::gmst;
+/// let theta = gmst(&Instant::from_unixtime(946728000.0));
+/// ```
+///
+pub fn gmst(tm: &Instant) -> f64 {
+    let tut1 = (tm.as_jd_utc() - 2451545.0) / 36525.0;
+    let secs = 67310.54841
+        + tut1 * ((876600.0 * 3600.0 + 8640184.812866) + tut1 * (0.093104 - tut1 * 6.2e-6));
+    // `secs` is negative before J2000, so wrap rather than use `%`
+    wrap_2pi(secs * std::f64::consts::TAU / 86400.0)
+}
+
+/// Greenwich Apparent Sidereal Time
+///
+/// # Arguments
+/// * `tm` - The instant at which to compute GAST
+///
+/// # Returns
+/// GAST in radians, in range [0, 2π)
+///
+/// # Notes
+/// * GMST plus the equation of the equinoxes, `eqeq`
+/// * UT1 is approximated as UTC (|UT1 - UTC| < 0.9 s)
+///
+/// # Example
+/// ```
+/// use satctrl::Instant;
+/// use satctrl::frametransform::gast;
+/// let theta = gast(&Instant::from_unixtime(946728000.0));
+/// ```
+///
+pub fn gast(tm: &Instant) -> f64 {
+    wrap_2pi(gmst(tm) + eqeq(tm))
+}
+
+/// Greenwich Mean Sidereal Time in sidereal hours
+///
+/// # Arguments
+/// * `tm` - The instant at which to compute GMST
+///
+/// # Returns
+/// GMST in hours, in range [0, 24)
+///
+pub fn gmst_hours(tm: &Instant) -> f64 {
+    radians_to_hours(gmst(tm))
+}
+
+/// Greenwich Apparent Sidereal Time in sidereal hours
+///
+/// # Arguments
+/// * `tm` - The instant at which to compute GAST
+///
+/// # Returns
+/// GAST in hours, in range [0, 24)
+///
+pub fn gast_hours(tm: &Instant) -> f64 {
+    radians_to_hours(gast(tm))
+}
+
+/// Convert an angle in [0, 2π) to hours in [0, 24)
+fn radians_to_hours(theta: f64) -> f64 {
+    let hours = theta * 12.0 / std::f64::consts::PI;
+    // Rounding can land exactly on 24
+    if hours >= 24.0 {
+        0.0
+    } else {
+        hours
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_era() {
+        // At 2000-01-01 12:00:00 UT1 the ERA is the defined constant
+        let tm = Instant::from_unixtime(946728000.0);
+        let expected = std::f64::consts::TAU * 0.7790572732640;
+        assert!((era(&tm) - expected).abs() < 1.0e-12);
+
+        // One UT1 day later the Earth has rotated slightly more than once
+        let tm = Instant::from_unixtime(946728000.0 + 86400.0);
+        let expected = std::f64::consts::TAU * (0.7790572732640 + 0.00273781191135448);
+        assert!((era(&tm) - expected).abs() < 1.0e-10);
+    }
+
+    #[test]
+    fn test_gast_2000() {
+        // SOFA iauGst00a test case: UT1 = TT = 2006-01-01 0h (TT is 65 s
+        // later here, a 0.0001 arcsec change), 1.754166138018281 rad
+        let tm = Instant::from_unixtime(1136073600.0);
+        assert!((gast_2000(&tm) - 1.754166138018281).abs() < 2.0e-8);
+
+        // Agrees with the equinox-based GAST once the IAU 2000 precession
+        // rate correction is removed
+        let mut tm = Instant::from_gregorian(1990, 1, 1, 0, 0, 0.0).unwrap();
+        for _ in 0..40 {
+            let d = gast_2000(&tm) - gast(&tm);
+            let d =
+                (d + std::f64::consts::PI).rem_euclid(std::f64::consts::TAU) - std::f64::consts::PI;
+            let rate = -0.275 * centuries_tt(&tm) * ARCSEC2RAD;
+            assert!((d - rate).abs() < 0.025 * ARCSEC2RAD);
+            tm = tm + crate::Duration::from_days(331.7);
+        }
+    }
+
+    #[test]
+    fn test_gmst() {
+        // Vallado Example 3-5: 1992-08-20 12:14:00 UT1
+        let tm = Instant::from_gregorian(1992, 8, 20, 12, 14, 0.0).unwrap();
+        let expected = 152.578787810_f64.to_radians();
+        assert!((gmst(&tm) - expected).abs() < 1.0e-9);
+    }
+
+    #[test]
+    fn test_sidereal_hours() {
+        let mut tm = Instant::from_gregorian(2021, 6, 1, 0, 0, 0.0).unwrap();
+        for _ in 0..30 {
+            let expected = wrap_2pi(gmst(&tm)) * 12.0 / std::f64::consts::PI;
+            assert!((gmst_hours(&tm) - expected).abs() < 1.0e-12);
+            assert!((0.0..24.0).contains(&gmst_hours(&tm)));
+            assert!((0.0..24.0).contains(&gast_hours(&tm)));
+            tm = tm + crate::Duration::from_hours(1.7);
+        }
+    }
+
+    #[test]
+    fn test_gast() {
+        // Meeus Example 12.a: 1987-04-10 0h UT
+        // Mean 13h10m46.3668s, apparent 13h10m46.1351s
+        let tm = Instant::from_gregorian(1987, 4, 10, 0, 0, 0.0).unwrap();
+        let mean = 13.0 + 10.0 / 60.0 + 46.3668 / 3600.0;
+        let apparent = 13.0 + 10.0 / 60.0 + 46.1351 / 3600.0;
+        assert!((gmst_hours(&tm) - mean).abs() < 1.0e-3 / 3600.0);
+        assert!((gast_hours(&tm) - apparent).abs() < 2.0e-3 / 3600.0);
+    }
+
+    #[test]
+    fn test_gmst_pre_j2000() {
+        // Negative centuries since J2000 must still give a value in [0, 2π)
+        let mut tm = Instant::from_gregorian(1995, 3, 1, 0, 0, 0.0).unwrap();
+        for _ in 0..48 {
+            let theta = gmst(&tm);
+            assert!((0.0..std::f64::consts::TAU).contains(&theta));
+            tm = tm + crate::Duration::from_minutes(30.0);
+        }
+    }
+}
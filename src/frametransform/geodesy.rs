@@ -0,0 +1,169 @@
+use crate::constants::{F_EARTH_WGS84, R_EARTH_MEAN, R_EARTH_WGS84};
+use crate::{SCError, SCResult};
+
+/// Great-circle distance between two points on a spherical Earth
+///
+/// Uses the haversine formula, which is well-conditioned for small distances
+///
+/// # Arguments
+/// * `lat1` - Latitude of the first point, radians
+/// * `lon1` - Longitude of the first point, radians
+/// * `lat2` - Latitude of the second point, radians
+/// * `lon2` - Longitude of the second point, radians
+///
+/// # Returns
+/// The great-circle distance in meters, using the mean Earth radius
+///
+/// # Example
+/// ```
+/// use satctrl::frametransform::haversine;
+/// let d = haversine(0.0, 0.0, 0.0, std::f64::consts::FRAC_PI_2);
+/// ```
+///
+pub fn haversine(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let sdlat = ((lat2 - lat1) / 2.0).sin();
+    let sdlon = ((lon2 - lon1) / 2.0).sin();
+    let h = sdlat * sdlat + lat1.cos() * lat2.cos() * sdlon * sdlon;
+    2.0 * R_EARTH_MEAN * h.sqrt().min(1.0).asin()
+}
+
+/// Geodesic distance between two points on the WGS84 ellipsoid
+///
+/// Uses Vincenty's inverse formula, accurate to well under a millimeter
+///
+/// # Arguments
+/// * `lat1` - Geodetic latitude of the first point, radians
+/// * `lon1` - Longitude of the first point, radians
+/// * `lat2` - Geodetic latitude of the second point, radians
+/// * `lon2` - Longitude of the second point, radians
+///
+/// # Returns
+/// The geodesic distance in meters, or `SCError::NotConverged` if the
+/// iteration fails, which happens for nearly antipodal points
+///
+/// # Example
+/// ```
+/// use satctrl::frametransform::vincenty;
+/// let d = vincenty(0.7, -1.2, 0.9, 0.1);
+/// ```
+///
+pub fn vincenty(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> SCResult<f64> {
+    let b = R_EARTH_WGS84 * (1.0 - F_EARTH_WGS84);
+    let l = lon2 - lon1;
+    let u1 = ((1.0 - F_EARTH_WGS84) * lat1.tan()).atan();
+    let u2 = ((1.0 - F_EARTH_WGS84) * lat2.tan()).atan();
+    let (sin_u1, cos_u1) = u1.sin_cos();
+    let (sin_u2, cos_u2) = u2.sin_cos();
+
+    let mut lambda = l;
+    for _ in 0..200 {
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        let sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+            + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+        .sqrt();
+        // Coincident points
+        if sin_sigma == 0.0 {
+            return Ok(0.0);
+        }
+        let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        let sigma = sin_sigma.atan2(cos_sigma);
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        let cos2_alpha = 1.0 - sin_alpha * sin_alpha;
+        // Both points on the equator
+        let cos_2sigma_m = if cos2_alpha != 0.0 {
+            cos_sigma - 2.0 * sin_u1 * sin_u2 / cos2_alpha
+        } else {
+            0.0
+        };
+        let c =
+            F_EARTH_WGS84 / 16.0 * cos2_alpha * (4.0 + F_EARTH_WGS84 * (4.0 - 3.0 * cos2_alpha));
+        let lambda_prev = lambda;
+        lambda = l
+            + (1.0 - c)
+                * F_EARTH_WGS84
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos_2sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))));
+
+        // Iteration runs away for nearly antipodal points
+        if lambda.abs() > std::f64::consts::PI + l.abs() {
+            break;
+        }
+        if (lambda - lambda_prev).abs() < 1.0e-12 {
+            let u2 = cos2_alpha * (R_EARTH_WGS84 * R_EARTH_WGS84 - b * b) / (b * b);
+            let aa = 1.0 + u2 / 16384.0 * (4096.0 + u2 * (-768.0 + u2 * (320.0 - 175.0 * u2)));
+            let bb = u2 / 1024.0 * (256.0 + u2 * (-128.0 + u2 * (74.0 - 47.0 * u2)));
+            let delta_sigma = bb
+                * sin_sigma
+                * (cos_2sigma_m
+                    + bb / 4.0
+                        * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))
+                            - bb / 6.0
+                                * cos_2sigma_m
+                                * (-3.0 + 4.0 * sin_sigma.powi(2))
+                                * (-3.0 + 4.0 * cos_2sigma_m.powi(2))));
+            return Ok(b * aa * (sigma - delta_sigma));
+        }
+    }
+    Err(SCError::NotConverged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dms(d: f64, m: f64, s: f64) -> f64 {
+        (d.abs() + m / 60.0 + s / 3600.0).copysign(d).to_radians()
+    }
+
+    #[test]
+    fn test_haversine() {
+        // A quarter of the equator
+        let d = haversine(0.0, 0.0, 0.0, std::f64::consts::FRAC_PI_2);
+        assert!((d - R_EARTH_MEAN * std::f64::consts::FRAC_PI_2).abs() < 1.0e-6);
+
+        // Pole to pole along a meridian
+        let d = haversine(
+            std::f64::consts::FRAC_PI_2,
+            0.0,
+            -std::f64::consts::FRAC_PI_2,
+            1.0,
+        );
+        assert!((d - R_EARTH_MEAN * std::f64::consts::PI).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn test_vincenty() {
+        // Flinders Peak to Buninyong, from Vincenty (1975)
+        let d = match vincenty(
+            dms(-37.0, 57.0, 3.72030),
+            dms(144.0, 25.0, 29.52440),
+            dms(-37.0, 39.0, 10.15610),
+            dms(143.0, 55.0, 35.38390),
+        ) {
+            Ok(d) => d,
+            Err(_) => panic!("Vincenty did not converge"),
+        };
+        assert!((d - 54972.271).abs() < 1.0e-3);
+
+        // Mid-latitude pair: sphere and ellipsoid agree to within 0.5%
+        let (lat1, lon1) = (42.36_f64.to_radians(), -71.06_f64.to_radians());
+        let (lat2, lon2) = (51.51_f64.to_radians(), -0.13_f64.to_radians());
+        let dv = match vincenty(lat1, lon1, lat2, lon2) {
+            Ok(d) => d,
+            Err(_) => panic!("Vincenty did not converge"),
+        };
+        let dh = haversine(lat1, lon1, lat2, lon2);
+        assert!(((dv - dh) / dv).abs() < 5.0e-3);
+
+        // Coincident points
+        assert!(matches!(vincenty(lat1, lon1, lat1, lon1), Ok(d) if d == 0.0));
+
+        // Nearly antipodal points do not converge
+        assert!(matches!(
+            vincenty(0.0, 0.0, 0.5e-5, std::f64::consts::PI - 0.5e-5),
+            Err(SCError::NotConverged)
+        ));
+    }
+}
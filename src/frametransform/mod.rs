@@ -0,0 +1,11 @@
+mod batch;
+mod earth_rotation;
+mod fk5;
+mod geodesy;
+mod state;
+
+pub use batch::{transform_batch, transform_trajectory};
+pub use earth_rotation::{era, gast, gast_2000, gast_hours, gmst, gmst_hours};
+pub use fk5::{eqeq, nutation_iau1980, precession_iau1976, qteme2gcrf};
+pub use geodesy::{haversine, vincenty};
+pub use state::transform_state;
@@ -0,0 +1,89 @@
+use crate::{Quaternion, Vector3};
+
+/// Transform a position and velocity between frames in relative rotation
+///
+/// Rotating the velocity alone is only correct between frames that do
+/// not rotate relative to each other. Between an Earth-fixed frame such
+/// as ITRF and an inertial frame, the transport term `ω × r` is of order
+/// 0.5 km/s at the surface and must be included:
+///
+/// `r' = q r` and `v' = q (v - ω × r)`
+///
+/// # Arguments
+/// * `q` - Rotation from the source frame to the destination frame
+/// * `omega` - Angular velocity of the destination frame relative to the
+///   source frame, expressed in the source frame, radians / second
+/// * `r` - Position in the source frame
+/// * `v` - Velocity in the source frame
+///
+/// # Returns
+/// The position and velocity in the destination frame
+///
+/// # Notes
+/// * Inertial to Earth-fixed: `omega` is the Earth rotation vector,
+///   `OMEGA_EARTH * ẑ`
+/// * Earth-fixed to inertial: `omega` is `-OMEGA_EARTH * ẑ`, with `q`
+///   the inverse rotation
+///
+/// # Example
+/// ```
+/// use satctrl::constants::OMEGA_EARTH;
+/// use satctrl::frametransform::transform_state;
+/// use satctrl::{Quaternion, Vector3};
+/// // A point fixed on the equator moves eastward in the inertial frame
+/// let r = Vector3::from_vec([6378.137e3, 0.0, 0.0]);
+/// let omega = Vector3::zhat() * -OMEGA_EARTH;
+/// let (_, v) = transform_state(&Quaternion::identity(), &omega, &r, &Vector3::zeros());
+/// assert!((v[1] - 465.1).abs() < 0.1);
+/// ```
+///
+pub fn transform_state(
+    q: &Quaternion,
+    omega: &Vector3,
+    r: &Vector3,
+    v: &Vector3,
+) -> (Vector3, Vector3) {
+    (*q * *r, *q * (*v - omega.cross(r)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{MU_EARTH, OMEGA_EARTH};
+
+    #[test]
+    fn test_transform_state() {
+        // Circular inclined orbit in the inertial frame
+        let a: f64 = 7.0e6;
+        let n = (MU_EARTH / a.powi(3)).sqrt();
+        let tilt = Quaternion::rotx(0.9);
+        let inertial = |t: f64| {
+            let (s, c) = (n * t).sin_cos();
+            (
+                tilt * Vector3::from_vec([a * c, a * s, 0.0]),
+                tilt * Vector3::from_vec([-a * n * s, a * n * c, 0.0]),
+            )
+        };
+        // Inertial to Earth-fixed rotation
+        let q = |t: f64| Quaternion::rotz(-(0.3 + OMEGA_EARTH * t));
+        let omega = Vector3::zhat() * OMEGA_EARTH;
+
+        let t = 1234.0;
+        let (ri, vi) = inertial(t);
+        let (rf, vf) = transform_state(&q(t), &omega, &ri, &vi);
+        assert!((rf - q(t) * ri).norm() < 1.0e-6);
+
+        // Central difference of the Earth-fixed positions
+        let h = 0.01;
+        let fixed = |t: f64| q(t) * inertial(t).0;
+        let vfd = (fixed(t + h) - fixed(t - h)) / (2.0 * h);
+        assert!((vf - vfd).norm() < 1.0e-4);
+        // Rotating the velocity alone is wrong by hundreds of m/s
+        assert!((q(t) * vi - vfd).norm() > 100.0);
+
+        // And back to inertial
+        let (r2, v2) = transform_state(&q(t).conjugate(), &(omega * -1.0), &rf, &vf);
+        assert!((r2 - ri).norm() < 1.0e-6);
+        assert!((v2 - vi).norm() < 1.0e-9);
+    }
+}
@@ -1,3 +1,11 @@
+use crate::Duration;
+use crate::{SCError, SCResult};
+
+/// Point in time, stored as integer microseconds of TAI since J2000
+///
+/// Equality, ordering, and hashing are those of the microsecond count,
+/// matching `Duration`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Instant {
     /// The number of microseconds since J2000 epoch
     /// (1st January 2000, 12:00:00)
@@ -5,23 +13,200 @@ pub struct Instant {
     pub raw: i64,
 }
 
+/// Time scales an Instant can be represented in
+///
+/// With the `serde` feature, serializes as its name, e.g. `"TT"`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeScale {
+    /// Coordinated Universal Time, with leap seconds
+    UTC,
+    /// International Atomic Time
+    TAI,
+    /// Terrestrial Time, TAI + 32.184 s
+    TT,
+    /// GPS time, TAI - 19 s
+    GPS,
+}
+
+impl TimeScale {
+    /// Constant offset from TAI in microseconds, for the uniform scales
+    fn offset_from_tai(&self) -> i64 {
+        match self {
+            TimeScale::UTC | TimeScale::TAI => 0,
+            TimeScale::TT => 32_184_000,
+            TimeScale::GPS => -19_000_000,
+        }
+    }
+}
+
+impl std::fmt::Display for TimeScale {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let name = match self {
+            TimeScale::UTC => "UTC",
+            TimeScale::TAI => "TAI",
+            TimeScale::TT => "TT",
+            TimeScale::GPS => "GPS",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Epochs of the numeric timestamps accepted by `Instant::parse_epoch`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EpochKind {
+    /// Unix time: seconds since 1970-01-01 00:00:00 UTC, not counting
+    /// leap seconds
+    Unix,
+    /// Continuous GPS seconds since 1980-01-06 00:00:00 UTC
+    GPS,
+    /// Continuous TAI seconds since the J2000 epoch
+    J2000,
+}
+
 /// Leap second table
-/// The first element is the number of microseconds since J2000 epoch
-/// The second element is the number of leap seconds to add
-const LEAP_SECOND_TABLE: [(i64, i64); 11] = [
-    (536544036000000, 37),  // 1st July 2017
-    (489024035000000, 36),  // 1st July 2015
-    (394416034000000, 35),  // 1st July 2012
-    (284083233000000, 34),  // 1st Jan 2009
-    (189388832000000, 33),  // 1st Jan 2006
-    (-31535969000000, 32),  // 1st Jan 1999
-    (-78969570000000, 31),  // 1st July 1997
-    (-126230371000000, 30), // 1st Jan 1996
-    (-173663972000000, 29), // 1st July 1994
-    (-220897973000000, 28), // 1st July 1993
-    (-205199973000000, 27), // 1st July 1992
+/// The first element is the number of microseconds since J2000 epoch (TAI)
+/// at which the leap second count takes effect
+/// The second element is the number of leap seconds (TAI - UTC) from that point on
+const LEAP_SECOND_TABLE: [(i64, i64); 28] = [
+    (536500837000000, 37),  // 1st Jan 2017
+    (488980836000000, 36),  // 1st July 2015
+    (394372835000000, 35),  // 1st July 2012
+    (284040034000000, 34),  // 1st Jan 2009
+    (189345633000000, 33),  // 1st Jan 2006
+    (-31579168000000, 32),  // 1st Jan 1999
+    (-79012769000000, 31),  // 1st July 1997
+    (-126273570000000, 30), // 1st Jan 1996
+    (-173707171000000, 29), // 1st July 1994
+    (-205243172000000, 28), // 1st July 1993
+    (-236779173000000, 27), // 1st July 1992
+    (-284039974000000, 26), // 1st Jan 1991
+    (-315575975000000, 25), // 1st Jan 1990
+    (-378734376000000, 24), // 1st Jan 1988
+    (-457703977000000, 23), // 1st July 1985
+    (-520862378000000, 22), // 1st July 1983
+    (-552398379000000, 21), // 1st July 1982
+    (-583934380000000, 20), // 1st July 1981
+    (-631195181000000, 19), // 1st Jan 1980
+    (-662731182000000, 18), // 1st Jan 1979
+    (-694267183000000, 17), // 1st Jan 1978
+    (-725803184000000, 16), // 1st Jan 1977
+    (-757425585000000, 15), // 1st Jan 1976
+    (-788961586000000, 14), // 1st Jan 1975
+    (-820497587000000, 13), // 1st Jan 1974
+    (-852033588000000, 12), // 1st Jan 1973
+    (-867931189000000, 11), // 1st July 1972
+    (-883655990000000, 10), // 1st Jan 1972
 ];
 
+/// Microseconds from the Unix epoch to J2000, not counting leap seconds
+const UNIX_TO_J2000_MICROSECONDS: i64 = 946_728_000_000_000;
+
+/// Julian date of the J2000 epoch
+const JD_J2000: f64 = 2451545.0;
+
+/// Microseconds per day
+const MICROSECONDS_PER_DAY: f64 = 86_400_000_000.0;
+
+/// Microseconds in a GPS week
+const MICROSECONDS_PER_WEEK: i64 = 604_800_000_000;
+
+/// Number of leap seconds (TAI - UTC) in effect at the given TAI time
+///
+/// Times before 1972 use the initial offset of 10 seconds
+fn leap_seconds_tai(raw: i64) -> i64 {
+    LEAP_SECOND_TABLE
+        .iter()
+        .find(|(t, _)| raw >= *t)
+        .map_or(10, |(_, ls)| *ls)
+}
+
+/// Number of leap seconds (TAI - UTC) in effect at the given UTC time,
+/// expressed as microseconds since J2000 without counting leap seconds
+///
+/// Times before 1972 use the initial offset of 10 seconds
+fn leap_seconds_utc(utc: i64) -> i64 {
+    LEAP_SECOND_TABLE
+        .iter()
+        .find(|(t, ls)| utc >= *t - *ls * 1_000_000)
+        .map_or(10, |(_, ls)| *ls)
+}
+
+/// True if the TAI time falls within an inserted leap second (23:59:60 UTC)
+fn in_leap_second(raw: i64) -> bool {
+    LEAP_SECOND_TABLE
+        .iter()
+        .any(|(t, _)| raw < *t && raw >= *t - 1_000_000)
+}
+
+/// Decimal seconds string, e.g. "-12.25", to integer microseconds
+///
+/// Digits past the microsecond are rounded half away from zero
+fn parse_microseconds(s: &str) -> SCResult<i64> {
+    let s = s.trim();
+    let (neg, s) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+    if int.is_empty() && frac.is_empty()
+        || !int.chars().chain(frac.chars()).all(|c| c.is_ascii_digit())
+    {
+        return Err(SCError::InvalidTimeString);
+    }
+    let secs = if int.is_empty() {
+        0
+    } else {
+        int.parse::<i64>().map_err(|_| SCError::InvalidTimeString)?
+    };
+    let mut usec = 0;
+    for (i, c) in frac.chars().take(7).enumerate() {
+        let digit = c as i64 - '0' as i64;
+        if i < 6 {
+            usec = usec * 10 + digit;
+        } else if digit >= 5 {
+            usec += 1;
+        }
+    }
+    for _ in frac.len()..6 {
+        usec *= 10;
+    }
+    let total = secs
+        .checked_mul(1_000_000)
+        .and_then(|x| x.checked_add(usec))
+        .ok_or(SCError::InvalidTimeString)?;
+    Ok(if neg { -total } else { total })
+}
+
+/// Days since 1970-01-01 of the given date in the proleptic Gregorian calendar
+///
+/// See: <https://howardhinnant.github.io/date_algorithms.html>
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Date in the proleptic Gregorian calendar given days since 1970-01-01
+///
+/// See: <https://howardhinnant.github.io/date_algorithms.html>
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
 impl Instant {
     /// Construct a new Instant from raw microseconds
     ///
@@ -41,10 +226,83 @@ impl Instant {
         Self { raw }
     }
 
+    /// Add a duration, checking for overflow
+    ///
+    /// # Arguments
+    /// * `d` - The duration to add
+    ///
+    /// # Returns
+    /// The new Instant, or None if the result is not representable
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::{Duration, Instant};
+    /// assert!(Instant::new(i64::MAX).checked_add(Duration::from_seconds(1.0)).is_none());
+    /// ```
+    pub fn checked_add(&self, d: Duration) -> Option<Self> {
+        self.raw.checked_add(d.usec).map(Self::new)
+    }
+
+    /// Whether two instants are within a tolerance of each other
+    ///
+    /// # Arguments
+    /// * `other` - The instant to compare against
+    /// * `tol` - The maximum allowed separation, inclusive
+    ///
+    /// # Returns
+    /// True if `|self - other| <= tol`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::{Duration, Instant};
+    /// let tm = Instant::new(1_000_000);
+    /// assert!(tm.is_close(&Instant::new(1_000_001), Duration::from_microseconds(1)));
+    /// ```
+    pub fn is_close(&self, other: &Instant, tol: Duration) -> bool {
+        self.raw.abs_diff(other.raw) <= tol.usec.unsigned_abs()
+    }
+
+    /// Subtract a duration, checking for overflow
+    ///
+    /// # Arguments
+    /// * `d` - The duration to subtract
+    ///
+    /// # Returns
+    /// The new Instant, or None if the result is not representable
+    pub fn checked_sub(&self, d: Duration) -> Option<Self> {
+        self.raw.checked_sub(d.usec).map(Self::new)
+    }
+
+    /// Elapsed time since another instant, checking for overflow
+    ///
+    /// # Arguments
+    /// * `earlier` - The instant to measure from
+    ///
+    /// # Returns
+    /// `self - earlier`, or None if the separation exceeds the `i64`
+    /// microsecond range of `Duration` (about ±292,000 years)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::{Duration, Instant};
+    /// let tm = Instant::J2000 + Duration::from_days(1.0);
+    /// assert_eq!(tm.checked_duration_since(&Instant::J2000), Some(Duration::from_days(1.0)));
+    /// assert!(Instant::new(i64::MAX).checked_duration_since(&Instant::new(-1)).is_none());
+    /// ```
+    pub fn checked_duration_since(&self, earlier: &Instant) -> Option<Duration> {
+        self.raw
+            .checked_sub(earlier.raw)
+            .map(Duration::from_microseconds)
+    }
+
     /// Construct a new Instant from GPS week and second of week
     ///
     /// # Arguments
-    /// * `week` - The GPS week number
+    /// * `week` - The full GPS week number since 1980-01-06, not truncated
+    ///   to the 10 or 13 bits broadcast in navigation messages
     /// * `sow` - The second of week
     ///
     /// # Returns
@@ -52,10 +310,51 @@ impl Instant {
     ///
     pub fn from_gps_week_and_sow(week: i32, sow: f64) -> Self {
         let week = week as i64;
-        let raw = week * 14_515_200_000_000 + (sow * 1.0e6) as i64 + Instant::GPS_EPOCH.raw;
+        let raw =
+            week * MICROSECONDS_PER_WEEK + (sow * 1.0e6).round() as i64 + Instant::GPS_EPOCH.raw;
         Self { raw }
     }
 
+    /// Seconds since the GPS epoch (1980-01-06 00:00:00 UTC)
+    ///
+    /// GPS time is a continuous scale offset from TAI by a constant 19
+    /// seconds, so leap seconds are counted as elapsed time and this value
+    /// differs from the UTC-based difference by the leap seconds inserted
+    /// since 1980
+    ///
+    /// # Returns
+    /// Continuous GPS seconds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Instant;
+    /// assert_eq!(Instant::GPS_EPOCH.as_gps_seconds(), 0.0);
+    /// ```
+    pub fn as_gps_seconds(&self) -> f64 {
+        (self.raw - Instant::GPS_EPOCH.raw) as f64 * 1.0e-6
+    }
+
+    /// GPS week and second of week
+    ///
+    /// # Returns
+    /// The full (not rolled-over) GPS week number and the second of week
+    /// in [0, 604800). For the 10-bit broadcast week, take `week % 1024`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Instant;
+    /// let tm = Instant::from_gps_week_and_sow(2300, 345600.5);
+    /// assert_eq!(tm.as_gps_week_and_sow(), (2300, 345600.5));
+    /// ```
+    pub fn as_gps_week_and_sow(&self) -> (i32, f64) {
+        let gps = self.raw - Instant::GPS_EPOCH.raw;
+        let week = gps.div_euclid(MICROSECONDS_PER_WEEK);
+        let sow = gps.rem_euclid(MICROSECONDS_PER_WEEK);
+        (week as i32, sow as f64 * 1.0e-6)
+    }
+
     /// Construct a new Instant from Unix time
     ///
     /// # Arguments
@@ -63,9 +362,68 @@ impl Instant {
     ///
     /// # Returns
     /// A new Instant object representing the input Unix time
+    ///
+    /// # Notes
+    /// Unix time does not count leap seconds; they are added here
+    /// to produce the TAI instant
     pub fn from_unixtime(unixtime: f64) -> Self {
-        let raw = (unixtime * 1.0e6) as i64 + Instant::UNIX_EPOCH.raw;
-        Self { raw }
+        Self::from_unix_microseconds((unixtime * 1.0e6) as i64)
+    }
+
+    /// Instant from microseconds of Unix time, adding leap seconds
+    fn from_unix_microseconds(unix: i64) -> Self {
+        let utc = unix - UNIX_TO_J2000_MICROSECONDS;
+        Self {
+            raw: utc + leap_seconds_utc(utc) * 1_000_000,
+        }
+    }
+
+    /// Parse a numeric timestamp counted from the given epoch
+    ///
+    /// # Arguments
+    /// * `s` - Integer or decimal seconds, optionally signed, e.g.
+    ///   `"1700000000"` or `"1700000000.125"`
+    /// * `kind` - The epoch and time scale the seconds are counted in
+    ///
+    /// # Returns
+    /// The instant, resolved to the microsecond, or
+    /// `SCError::InvalidTimeString` if the string is not a number of
+    /// seconds or is out of range
+    ///
+    /// # Notes
+    /// The digits are parsed directly rather than through `f64`, so large
+    /// timestamps keep their full microsecond precision
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::{EpochKind, Instant};
+    /// let tm = Instant::parse_epoch("0.5", EpochKind::GPS).unwrap();
+    /// assert_eq!(tm.as_gps_seconds(), 0.5);
+    /// ```
+    pub fn parse_epoch(s: &str, kind: EpochKind) -> SCResult<Self> {
+        let usec = parse_microseconds(s)?;
+        let raw = match kind {
+            EpochKind::Unix => {
+                if usec.checked_sub(UNIX_TO_J2000_MICROSECONDS).is_none() {
+                    return Err(SCError::InvalidTimeString);
+                }
+                return Ok(Self::from_unix_microseconds(usec));
+            }
+            EpochKind::GPS => usec.checked_add(Instant::GPS_EPOCH.raw),
+            EpochKind::J2000 => Some(usec),
+        };
+        raw.map(Self::new).ok_or(SCError::InvalidTimeString)
+    }
+
+    /// Parse a Unix timestamp string; see `parse_epoch`
+    pub fn from_unix_seconds_str(s: &str) -> SCResult<Self> {
+        Self::parse_epoch(s, EpochKind::Unix)
+    }
+
+    /// Parse a GPS seconds string; see `parse_epoch`
+    pub fn from_gps_seconds_str(s: &str) -> SCResult<Self> {
+        Self::parse_epoch(s, EpochKind::GPS)
     }
 
     /// Convert Instant to Unix time
@@ -73,7 +431,277 @@ impl Instant {
     /// # Returns
     /// The Unix time in seconds (since 1970-01-01 00:00:00 UTC)
     pub fn as_unixtime(&self) -> f64 {
-        (self.raw - Instant::UNIX_EPOCH.raw) as f64 * 1.0e-6
+        (self.utc_microseconds() + UNIX_TO_J2000_MICROSECONDS) as f64 * 1.0e-6
+    }
+
+    /// Julian date in the UTC time scale
+    ///
+    /// # Returns
+    /// The Julian date, in days, of the instant in UTC
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Instant;
+    /// let tm = Instant::from_unixtime(946728000.0);
+    /// assert_eq!(tm.as_jd_utc(), 2451545.0);
+    /// ```
+    ///
+    pub fn as_jd_utc(&self) -> f64 {
+        JD_J2000 + self.utc_microseconds() as f64 / MICROSECONDS_PER_DAY
+    }
+
+    /// Julian date in the Terrestrial Time (TT) scale
+    ///
+    /// # Returns
+    /// The Julian date, in days, of the instant in TT = TAI + 32.184 s
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Instant;
+    /// let tm = Instant::from_unixtime(946728000.0);
+    /// assert!((tm.as_jd_tt() - (2451545.0 + 64.184 / 86400.0)).abs() < 1.0e-9);
+    /// ```
+    ///
+    pub fn as_jd_tt(&self) -> f64 {
+        JD_J2000 + (self.raw + TimeScale::TT.offset_from_tai()) as f64 / MICROSECONDS_PER_DAY
+    }
+
+    /// Number of leap seconds (TAI - UTC) in effect at the instant
+    ///
+    /// # Returns
+    /// TAI - UTC in whole seconds; 10 before 1972
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Instant;
+    /// let tm = Instant::from_gregorian(2024, 1, 1, 0, 0, 0.0).unwrap();
+    /// assert_eq!(tm.leap_seconds_at(), 37);
+    /// ```
+    pub fn leap_seconds_at(&self) -> i64 {
+        leap_seconds_tai(self.raw)
+    }
+
+    /// Epochs at which the leap second count changes
+    ///
+    /// # Returns
+    /// An iterator, in chronological order, over the instant each count
+    /// takes effect (00:00:00 UTC after the inserted leap second) and the
+    /// new TAI - UTC in seconds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Instant;
+    /// let (tm, count) = Instant::leap_second_epochs().last().unwrap();
+    /// assert_eq!(tm.to_string(), "2017-01-01T00:00:00.000000Z");
+    /// assert_eq!(count, 37);
+    /// ```
+    pub fn leap_second_epochs() -> impl Iterator<Item = (Instant, i64)> {
+        LEAP_SECOND_TABLE
+            .iter()
+            .rev()
+            .map(|(t, ls)| (Instant::new(*t), *ls))
+    }
+
+    /// Microseconds since J2000 in UTC, not counting leap seconds
+    fn utc_microseconds(&self) -> i64 {
+        self.raw - leap_seconds_tai(self.raw) * 1_000_000
+    }
+
+    /// Construct a new Instant from a UTC Gregorian date and time
+    ///
+    /// # Arguments
+    /// * `year` - The year
+    /// * `month` - The month, 1 to 12
+    /// * `day` - The day of month, 1 to 31
+    /// * `hour` - The hour, 0 to 23
+    /// * `minute` - The minute, 0 to 59
+    /// * `second` - The second, in range [0, 60), or [60, 61) during an
+    ///   inserted leap second
+    ///
+    /// # Returns
+    /// A new Instant, or `SCError::InvalidInput` if any field is out of range
+    /// or a second of 60 does not fall on a leap second
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Instant;
+    /// let tm = Instant::from_gregorian(2000, 1, 1, 12, 0, 0.0).unwrap();
+    /// assert_eq!(tm.as_jd_utc(), 2451545.0);
+    /// ```
+    ///
+    pub fn from_gregorian(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: f64,
+    ) -> SCResult<Self> {
+        let (year, month, day) = (year as i64, month as i64, day as i64);
+        let days = days_from_civil(year, month, day);
+        if civil_from_days(days) != (year, month, day)
+            || hour > 23
+            || minute > 59
+            || !(0.0..61.0).contains(&second)
+        {
+            return Err(SCError::InvalidInput);
+        }
+        // A leap second is one second past 23:59:59 in the old offset
+        let leap = second >= 60.0;
+        let second = if leap { second - 1.0 } else { second };
+        let unix = days * 86_400_000_000
+            + hour as i64 * 3_600_000_000
+            + minute as i64 * 60_000_000
+            + (second * 1.0e6).round() as i64;
+        let utc = unix - UNIX_TO_J2000_MICROSECONDS;
+        let raw = utc + leap_seconds_utc(utc) * 1_000_000;
+        if !leap {
+            return Ok(Self { raw });
+        }
+        let raw = raw + 1_000_000;
+        if !in_leap_second(raw) {
+            return Err(SCError::InvalidInput);
+        }
+        Ok(Self { raw })
+    }
+
+    /// Create an Instant from a UTC year and fractional day of year
+    ///
+    /// Day 1.0 is January 1 at 00:00:00 UTC; the fractional part of the
+    /// day encodes the time of day, as in GNSS and space-weather products
+    ///
+    /// # Arguments
+    /// * `year` - The year
+    /// * `doy` - The day of year, starting at 1.0
+    ///
+    /// # Returns
+    /// A new Instant. Days past the end of the year continue into the next
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Instant;
+    /// let tm = Instant::from_year_day_of_year(2024, 1.5);
+    /// assert_eq!(tm, Instant::from_gregorian(2024, 1, 1, 12, 0, 0.0).unwrap());
+    /// ```
+    ///
+    pub fn from_year_day_of_year(year: i32, doy: f64) -> Self {
+        let days = days_from_civil(year as i64, 1, 1);
+        let unix = days * 86_400_000_000 + ((doy - 1.0) * 86_400_000_000.0).round() as i64;
+        let utc = unix - UNIX_TO_J2000_MICROSECONDS;
+        Self {
+            raw: utc + leap_seconds_utc(utc) * 1_000_000,
+        }
+    }
+
+    /// Gregorian date and time as integer fields
+    ///
+    /// # Arguments
+    /// * `scale` - The time scale of the calendar representation
+    ///
+    /// # Returns
+    /// year, month, day, hour, minute, second, and microsecond. The second
+    /// is 60 during an inserted UTC leap second
+    fn gregorian_fields(&self, scale: TimeScale) -> (i64, i64, i64, i64, i64, i64, i64) {
+        let leap = scale == TimeScale::UTC && in_leap_second(self.raw);
+        let mut unix = match scale {
+            TimeScale::UTC => self.utc_microseconds(),
+            _ => self.raw + scale.offset_from_tai(),
+        } + UNIX_TO_J2000_MICROSECONDS;
+        if leap {
+            // Show the leap second as an extension of the previous minute
+            unix -= 1_000_000;
+        }
+        let (year, month, day) = civil_from_days(unix.div_euclid(86_400_000_000));
+        let us = unix.rem_euclid(86_400_000_000);
+        let second = if leap { 60 } else { (us / 1_000_000) % 60 };
+        (
+            year,
+            month,
+            day,
+            us / 3_600_000_000,
+            (us / 60_000_000) % 60,
+            second,
+            us % 1_000_000,
+        )
+    }
+
+    /// UTC Gregorian date and time
+    ///
+    /// # Returns
+    /// A tuple of year, month, day, hour, minute, and second. The
+    /// second is in range [60, 61) during an inserted leap second
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Instant;
+    /// let tm = Instant::from_gregorian(2024, 3, 15, 6, 30, 12.5).unwrap();
+    /// assert_eq!(tm.as_gregorian(), (2024, 3, 15, 6, 30, 12.5));
+    /// ```
+    ///
+    pub fn as_gregorian(&self) -> (i32, u32, u32, u32, u32, f64) {
+        let (year, month, day, hour, minute, second, us) = self.gregorian_fields(TimeScale::UTC);
+        (
+            year as i32,
+            month as u32,
+            day as u32,
+            hour as u32,
+            minute as u32,
+            second as f64 + us as f64 * 1.0e-6,
+        )
+    }
+
+    /// ISO 8601 representation in the given time scale
+    ///
+    /// # Arguments
+    /// * `scale` - The time scale of the calendar representation
+    ///
+    /// # Returns
+    /// The date and time in `scale`. UTC uses the `Display` format with a
+    /// "Z" suffix; other scales are suffixed with the scale name
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::{Instant, TimeScale};
+    /// let tm = Instant::from_gregorian(2024, 3, 15, 6, 30, 0.0).unwrap();
+    /// assert_eq!(tm.to_string_with_scale(TimeScale::TAI), "2024-03-15T06:30:37.000000 TAI");
+    /// ```
+    pub fn to_string_with_scale(&self, scale: TimeScale) -> String {
+        if scale == TimeScale::UTC {
+            return self.to_string();
+        }
+        let (year, month, day, hour, minute, second, us) = self.gregorian_fields(scale);
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:06} {}",
+            year, month, day, hour, minute, second, us, scale
+        )
+    }
+
+    /// RFC 3339 UTC string with the given number of fractional-second digits
+    ///
+    /// # Arguments
+    /// * `digits` - Number of digits after the decimal point; 0 omits the
+    ///   decimal point. Digits beyond microseconds are zero
+    ///
+    /// # Returns
+    /// The string, e.g. "2024-03-15T06:30:12.500Z" for 3 digits
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Instant;
+    /// let tm = Instant::from_gregorian(2024, 3, 15, 6, 30, 12.5).unwrap();
+    /// assert_eq!(tm.to_rfc3339(3), "2024-03-15T06:30:12.500Z");
+    /// ```
+    pub fn to_rfc3339(&self, digits: usize) -> String {
+        format!("{:.*}", digits, self)
     }
 
     /// J2000 epoch is 2000-01-01 12:00:00 TAI
@@ -81,17 +709,685 @@ impl Instant {
 
     /// Unix epoch is 1970-01-01 00:00:00 UTC
     pub const UNIX_EPOCH: Self = Instant {
-        raw: -946684768000000,
+        raw: -946727990000000,
     };
 
     /// GPS epoch is 1980-01-06 00:00:00 UTC
     pub const GPS_EPOCH: Self = Instant {
-        raw: -630719981000000,
+        raw: -630763181000000,
     };
 
+    /// The current time from the system clock
+    ///
+    /// The system clock counts Unix time (UTC without leap seconds); the
+    /// leap seconds in effect are added to give the TAI instant
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Instant;
+    /// let now = Instant::now();
+    /// assert!(now.raw > Instant::from_gregorian(2024, 1, 1, 0, 0, 0.0).unwrap().raw);
+    /// ```
     pub fn now() -> Self {
-        let now = std::time::SystemTime::now();
-        let since_epoch = now.duration_since(std::time::UNIX_EPOCH).unwrap();
-        Self::new(since_epoch.as_nanos() as i64)
+        let unix = match std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+            Ok(d) => d.as_micros() as i64,
+            Err(e) => -(e.duration().as_micros() as i64),
+        };
+        Self::from_unix_microseconds(unix)
+    }
+
+    /// The current time from the system clock, never decreasing
+    ///
+    /// Like `now`, but each call returns an instant no earlier than any
+    /// previous call in this process. Unix time repeats a second when a
+    /// leap second is inserted (and the system clock may be stepped back),
+    /// which would otherwise make the continuous TAI count run backwards;
+    /// here the time holds instead until the clock catches up
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use satctrl::Instant;
+    /// let t0 = Instant::now_tai();
+    /// let t1 = Instant::now_tai();
+    /// assert!(t1.raw >= t0.raw);
+    /// ```
+    pub fn now_tai() -> Self {
+        use std::sync::atomic::{AtomicI64, Ordering};
+        static LATEST: AtomicI64 = AtomicI64::new(i64::MIN);
+        let raw = Self::now().raw;
+        Self::new(LATEST.fetch_max(raw, Ordering::Relaxed).max(raw))
+    }
+}
+
+/// Add a duration to an instant
+///
+/// Overflows (panicking in debug builds) if the result is outside the
+/// range of `i64` microseconds; use `checked_add` for untrusted input
+impl std::ops::Add<Duration> for Instant {
+    type Output = Instant;
+    fn add(self, d: Duration) -> Instant {
+        Instant {
+            raw: self.raw + d.usec,
+        }
+    }
+}
+
+/// Subtract a duration from an instant
+///
+/// Overflows (panicking in debug builds) if the result is outside the
+/// range of `i64` microseconds; use `checked_sub` for untrusted input
+impl std::ops::Sub<Duration> for Instant {
+    type Output = Instant;
+    fn sub(self, d: Duration) -> Instant {
+        Instant {
+            raw: self.raw - d.usec,
+        }
+    }
+}
+
+/// Elapsed time between two instants, including leap seconds
+///
+/// Valid for separations within the `i64` microsecond range of
+/// `Duration` (about ±292,000 years); beyond that the subtraction
+/// overflows. Use `Instant::checked_duration_since` for arbitrary inputs
+impl std::ops::Sub<Instant> for Instant {
+    type Output = Duration;
+    fn sub(self, other: Instant) -> Duration {
+        Duration::from_microseconds(self.raw - other.raw)
+    }
+}
+
+/// Display the instant as an ISO 8601 UTC string
+///
+/// The formatter precision sets the number of fractional-second digits,
+/// defaulting to 6 (microseconds). Digits are truncated, not rounded, so
+/// the displayed time never runs ahead of the instant
+///
+/// # Example
+///
+/// ```
+/// use satctrl::Instant;
+/// let tm = Instant::from_gregorian(2024, 3, 15, 6, 30, 12.5).unwrap();
+/// assert_eq!(tm.to_string(), "2024-03-15T06:30:12.500000Z");
+/// assert_eq!(format!("{:.3}", tm), "2024-03-15T06:30:12.500Z");
+/// ```
+impl std::fmt::Display for Instant {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (year, month, day, hour, minute, second, us) = self.gregorian_fields(TimeScale::UTC);
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            year, month, day, hour, minute, second
+        )?;
+        match f.precision().unwrap_or(6) {
+            0 => {}
+            digits @ 1..=6 => write!(
+                f,
+                ".{:0width$}",
+                us / 10_i64.pow(6 - digits as u32),
+                width = digits
+            )?,
+            digits => write!(f, ".{:06}{}", us, "0".repeat(digits - 6))?,
+        }
+        write!(f, "Z")
+    }
+}
+
+/// Parse an ISO 8601 UTC string, e.g. "2024-03-15T06:30:12.5Z"
+///
+/// The date and time may be separated by 'T' or a space, the trailing
+/// 'Z' is optional, and the time of day may be omitted. The date may also
+/// be given as a year and 3-digit day of year, e.g. "2024-075"
+///
+/// # Example
+///
+/// ```
+/// use satctrl::Instant;
+/// let tm: Instant = "2024-03-15T06:30:12.5Z".parse().unwrap();
+/// ```
+impl std::str::FromStr for Instant {
+    type Err = SCError;
+
+    fn from_str(s: &str) -> SCResult<Self> {
+        let s = s.trim();
+        let s = s.strip_suffix(['Z', 'z']).unwrap_or(s);
+        let (date, time) = s.split_once(['T', ' ']).unwrap_or((s, "00:00:00"));
+
+        let date: Vec<&str> = date.split('-').collect();
+        let time: Vec<&str> = time.split(':').collect();
+        let ordinal = date.len() == 2 && date[1].len() == 3;
+        if !(date.len() == 3 || ordinal) || time.len() != 3 {
+            return Err(SCError::InvalidTimeString);
+        }
+        // Reject signs and other characters the numeric parsers would accept
+        if !date
+            .iter()
+            .chain(time.iter())
+            .all(|f| !f.is_empty() && f.chars().all(|c| c.is_ascii_digit() || c == '.'))
+        {
+            return Err(SCError::InvalidTimeString);
+        }
+        let field = |f: &str| f.parse::<u32>().map_err(|_| SCError::InvalidTimeString);
+        let year = date[0]
+            .parse::<i32>()
+            .map_err(|_| SCError::InvalidTimeString)?;
+        let second = time[2]
+            .parse::<f64>()
+            .map_err(|_| SCError::InvalidTimeString)?;
+        let (month, day) = if ordinal {
+            let doy = field(date[1])? as i64;
+            let jan1 = days_from_civil(year as i64, 1, 1);
+            let (y, m, d) = civil_from_days(jan1 + doy - 1);
+            if doy < 1 || y != year as i64 {
+                return Err(SCError::InvalidTimeString);
+            }
+            (m as u32, d as u32)
+        } else {
+            (field(date[1])?, field(date[2])?)
+        };
+        Instant::from_gregorian(year, month, day, field(time[0])?, field(time[1])?, second)
+            .map_err(|_| SCError::InvalidTimeString)
+    }
+}
+
+/// Serialize as an ISO 8601 UTC string, matching `Display`
+#[cfg(feature = "serde")]
+impl serde::Serialize for Instant {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserialize from an ISO 8601 UTC string
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Instant {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <std::borrow::Cow<str>>::deserialize(deserializer)?;
+        s.parse()
+            .map_err(|_| serde::de::Error::custom(format!("invalid time string: {}", s)))
+    }
+}
+
+/// Convert from a chrono UTC timestamp
+///
+/// chrono represents a leap second as a nanosecond field of one second or
+/// more within second :59; that instant maps onto the inserted leap second.
+/// A leap second that is not in the leap second table maps onto the first
+/// second of the following minute. Nanoseconds are rounded to the nearest
+/// microsecond
+///
+/// # Example
+///
+/// ```ignore
+/// let dt = chrono::DateTime::parse_from_rfc3339("2024-03-15T06:30:12.5Z").unwrap();
+/// let tm = satctrl::Instant::from(dt.to_utc());
+/// ```
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for Instant {
+    fn from(dt: chrono::DateTime<chrono::Utc>) -> Self {
+        // `timestamp` counts a leap second as part of second :59
+        let usec = (dt.timestamp_subsec_nanos() as i64 + 500) / 1000;
+        Instant::from_unixtime(dt.timestamp() as f64) + Duration::from_microseconds(usec)
+    }
+}
+
+/// Convert to a chrono UTC timestamp
+///
+/// An instant within a leap second is returned as second :59 with a
+/// nanosecond field of one second or more, chrono's leap second
+/// representation. Returns `SCError::InvalidOutput` if the instant is
+/// outside the range chrono can represent
+#[cfg(feature = "chrono")]
+impl TryFrom<Instant> for chrono::DateTime<chrono::Utc> {
+    type Error = SCError;
+
+    fn try_from(tm: Instant) -> SCResult<Self> {
+        let (year, month, day, hour, minute, second, usec) = tm.gregorian_fields(TimeScale::UTC);
+        let (second, usec) = match second {
+            60 => (59, usec + 1_000_000),
+            _ => (second, usec),
+        };
+        chrono::NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32)
+            .and_then(|d| {
+                d.and_hms_micro_opt(hour as u32, minute as u32, second as u32, usec as u32)
+            })
+            .map(|dt| dt.and_utc())
+            .ok_or(SCError::InvalidOutput)
+    }
+}
+
+/// Serialize an Instant as raw microseconds since J2000 rather than a string
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Config {
+///     #[serde(with = "satctrl::instant_raw")]
+///     epoch: satctrl::Instant,
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub mod instant_raw {
+    use super::Instant;
+
+    pub fn serialize<S: serde::Serializer>(tm: &Instant, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(tm.raw)
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Instant, D::Error> {
+        let raw = <i64 as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(Instant::new(raw))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_epochs() {
+        assert_eq!(Instant::UNIX_EPOCH.as_unixtime(), 0.0);
+        assert_eq!(Instant::GPS_EPOCH.as_unixtime(), 315964800.0);
+        assert_eq!(
+            Instant::from_unixtime(315964800.0).raw,
+            Instant::GPS_EPOCH.raw
+        );
+        // 2000-01-01 12:00:00 UTC is 32 seconds after J2000 (TAI)
+        assert_eq!(Instant::from_unixtime(946728000.0).raw, 32_000_000);
+    }
+
+    #[test]
+    fn test_leap_seconds() {
+        // One second before and at the start of 2017-01-01 UTC
+        let before = Instant::from_unixtime(1483228799.0);
+        let after = Instant::from_unixtime(1483228800.0);
+        // The leap second 2016-12-31 23:59:60 sits between them
+        assert_eq!(after.raw - before.raw, 2_000_000);
+        assert_eq!(leap_seconds_tai(before.raw), 36);
+        assert_eq!(leap_seconds_tai(after.raw), 37);
+    }
+
+    #[test]
+    fn test_leap_second_epochs() {
+        let tm = Instant::from_gregorian(2024, 6, 1, 0, 0, 0.0).unwrap();
+        assert_eq!(tm.leap_seconds_at(), 37);
+        let tm = Instant::from_gregorian(1970, 1, 1, 0, 0, 0.0).unwrap();
+        assert_eq!(tm.leap_seconds_at(), 10);
+
+        let epochs: Vec<(Instant, i64)> = Instant::leap_second_epochs().collect();
+        assert_eq!(epochs.len(), 28);
+        assert_eq!(epochs[0].0.to_string(), "1972-01-01T00:00:00.000000Z");
+        assert_eq!(epochs[0].1, 10);
+        for pair in epochs.windows(2) {
+            assert!(pair[1].0.raw > pair[0].0.raw);
+            assert_eq!(pair[1].1, pair[0].1 + 1);
+            // Each count takes effect at midnight UTC, just after a leap second
+            let (_, _, _, hour, minute, second) = pair[1].0.as_gregorian();
+            assert_eq!((hour, minute, second), (0, 0, 0.0));
+            assert_eq!(pair[1].0.leap_seconds_at(), pair[1].1);
+            assert_eq!(Instant::new(pair[1].0.raw - 1).leap_seconds_at(), pair[0].1);
+        }
+    }
+
+    #[test]
+    fn test_gregorian() {
+        let tm = Instant::from_gregorian(2000, 1, 1, 12, 0, 0.0).unwrap();
+        assert_eq!(tm.raw, 32_000_000);
+        assert_eq!(tm.as_gregorian(), (2000, 1, 1, 12, 0, 0.0));
+
+        let tm = Instant::from_gregorian(1995, 6, 30, 23, 59, 59.25).unwrap();
+        assert_eq!(tm.as_gregorian(), (1995, 6, 30, 23, 59, 59.25));
+        assert_eq!(tm.to_string(), "1995-06-30T23:59:59.250000Z");
+
+        assert!(Instant::from_gregorian(2023, 2, 29, 0, 0, 0.0).is_err());
+        assert!(Instant::from_gregorian(2024, 2, 29, 0, 0, 0.0).is_ok());
+        assert!(Instant::from_gregorian(2024, 1, 1, 24, 0, 0.0).is_err());
+
+        // Second 60 only during an inserted leap second
+        let leap = Instant::from_gregorian(2016, 12, 31, 23, 59, 60.5).unwrap();
+        assert_eq!(leap.as_gregorian(), (2016, 12, 31, 23, 59, 60.5));
+        assert!(Instant::from_gregorian(2016, 12, 31, 23, 58, 60.0).is_err());
+        assert!(Instant::from_gregorian(2017, 12, 31, 23, 59, 60.0).is_err());
+        assert!(Instant::from_gregorian(2016, 12, 31, 23, 59, 61.0).is_err());
+    }
+
+    #[test]
+    fn test_display_precision() {
+        let tm = Instant::from_gregorian(2024, 3, 15, 6, 30, 12.123456).unwrap();
+        assert_eq!(format!("{}", tm), "2024-03-15T06:30:12.123456Z");
+        assert_eq!(format!("{:.6}", tm), "2024-03-15T06:30:12.123456Z");
+        assert_eq!(format!("{:.3}", tm), "2024-03-15T06:30:12.123Z");
+        assert_eq!(format!("{:.0}", tm), "2024-03-15T06:30:12Z");
+        assert_eq!(tm.to_rfc3339(3), "2024-03-15T06:30:12.123Z");
+        assert_eq!(tm.to_rfc3339(6), "2024-03-15T06:30:12.123456Z");
+        assert_eq!(tm.to_rfc3339(9), "2024-03-15T06:30:12.123456000Z");
+
+        // Truncated, so the seconds never roll over
+        let tm = Instant::from_gregorian(2024, 12, 31, 23, 59, 59.9999).unwrap();
+        assert_eq!(tm.to_rfc3339(3), "2024-12-31T23:59:59.999Z");
+        let after = Instant::from_gregorian(2017, 1, 1, 0, 0, 0.0).unwrap();
+        let leap = Instant::new(after.raw - 250_000);
+        assert_eq!(leap.to_rfc3339(1), "2016-12-31T23:59:60.7Z");
+        assert_eq!(leap.to_rfc3339(3).parse::<Instant>().unwrap(), leap);
+    }
+
+    #[test]
+    fn test_display_leap_second() {
+        let after = Instant::from_gregorian(2017, 1, 1, 0, 0, 0.0).unwrap();
+        let leap = Instant::new(after.raw - 500_000);
+        assert_eq!(leap.to_string(), "2016-12-31T23:59:60.500000Z");
+        assert_eq!(after.to_string(), "2017-01-01T00:00:00.000000Z");
+    }
+
+    #[test]
+    fn test_parse() {
+        let tm = Instant::from_gregorian(2024, 3, 15, 6, 30, 12.5).unwrap();
+        assert_eq!("2024-03-15T06:30:12.5Z".parse::<Instant>().unwrap(), tm);
+        assert_eq!("2024-03-15 06:30:12.500".parse::<Instant>().unwrap(), tm);
+        assert_eq!(tm.to_string().parse::<Instant>().unwrap(), tm);
+        assert_eq!(
+            "2024-03-15".parse::<Instant>().unwrap(),
+            Instant::from_gregorian(2024, 3, 15, 0, 0, 0.0).unwrap()
+        );
+        // Year and day of year
+        assert_eq!("2024-075T06:30:12.5Z".parse::<Instant>().unwrap(), tm);
+        assert_eq!(
+            "2023-365".parse::<Instant>().unwrap(),
+            Instant::from_gregorian(2023, 12, 31, 0, 0, 0.0).unwrap()
+        );
+        // Leap second, which falls between 23:59:59 and midnight
+        let leap = "2016-12-31T23:59:60Z".parse::<Instant>().unwrap();
+        let before = Instant::from_gregorian(2016, 12, 31, 23, 59, 59.0).unwrap();
+        let after = Instant::from_gregorian(2017, 1, 1, 0, 0, 0.0).unwrap();
+        assert_eq!(leap.raw - before.raw, 1_000_000);
+        assert_eq!(after.raw - leap.raw, 1_000_000);
+        assert_eq!(leap.to_string(), "2016-12-31T23:59:60.000000Z");
+        assert_eq!(leap.to_string().parse::<Instant>().unwrap(), leap);
+
+        for bad in [
+            "",
+            "2024-03",
+            "2023-366",
+            "2015-12-31T23:59:60Z",
+            "2016-12-31T12:00:60Z",
+            "2024-000",
+            "2024-13-01T00:00:00",
+            "2024-03-15T06:30",
+            "x024-03-15",
+        ] {
+            assert!(matches!(
+                bad.parse::<Instant>(),
+                Err(SCError::InvalidTimeString)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_now() {
+        let unix = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs_f64();
+        let now = Instant::now();
+        assert!((now.as_unixtime() - unix).abs() < 1.0);
+        // TAI - UTC has been 37 seconds since 2017
+        let tai = unix - 946_728_000.0 + 37.0;
+        assert!((now.raw as f64 * 1.0e-6 - tai).abs() < 1.0);
+
+        let mut prev = Instant::now_tai();
+        for _ in 0..1000 {
+            let t = Instant::now_tai();
+            assert!(t.raw >= prev.raw);
+            prev = t;
+        }
+        assert!((prev - now).as_seconds().abs() < 1.0);
+    }
+
+    #[test]
+    fn test_from_year_day_of_year() {
+        assert_eq!(
+            Instant::from_year_day_of_year(2024, 1.5),
+            Instant::from_gregorian(2024, 1, 1, 12, 0, 0.0).unwrap()
+        );
+        // 2024 is a leap year
+        assert_eq!(
+            Instant::from_year_day_of_year(2024, 366.25),
+            Instant::from_gregorian(2024, 12, 31, 6, 0, 0.0).unwrap()
+        );
+        assert_eq!(
+            Instant::from_year_day_of_year(2017, 1.0),
+            Instant::from_gregorian(2017, 1, 1, 0, 0, 0.0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let one = Duration::from_microseconds(1);
+        let max = Instant::new(i64::MAX);
+        let min = Instant::new(i64::MIN);
+        assert!(max.checked_add(one).is_none());
+        assert!(min.checked_sub(one).is_none());
+        assert!(min.checked_add(-one).is_none());
+        assert_eq!(max.checked_sub(one), Some(Instant::new(i64::MAX - 1)));
+        assert_eq!(Instant::new(i64::MAX - 1).checked_add(one), Some(max));
+
+        // Far-future durations saturate, then fail the checked add
+        let huge = Duration::from_days(1.0e12);
+        assert_eq!(huge.usec, i64::MAX);
+        assert!(Instant::new(1).checked_add(huge).is_none());
+        assert!(Instant::new(-1).checked_sub(huge).is_some());
+        assert!(Instant::new(-2).checked_sub(huge).is_none());
+    }
+
+    #[test]
+    fn test_gps() {
+        // 2024-01-01 00:00:00 UTC is GPS week 2295, day 1, with 18 leap
+        // seconds between GPS and UTC
+        let tm = Instant::from_gregorian(2024, 1, 1, 0, 0, 0.0).unwrap();
+        let (week, sow) = tm.as_gps_week_and_sow();
+        assert_eq!(week, 2295);
+        assert_eq!(sow, 86400.0 + 18.0);
+        assert_eq!(Instant::from_gps_week_and_sow(week, sow), tm);
+        assert_eq!(tm.as_gps_seconds(), 2295.0 * 604800.0 + sow);
+
+        // Round trip across the 1024-week rollovers
+        for week in [0, 1023, 1024, 2047, 2048, 3000] {
+            for sow in [0.0, 0.25, 302400.0, 604799.999999] {
+                let tm = Instant::from_gps_week_and_sow(week, sow);
+                let (w, s) = tm.as_gps_week_and_sow();
+                assert_eq!(w, week);
+                assert!((s - sow).abs() < 1.0e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_epoch() {
+        let tm = Instant::from_unix_seconds_str("1704067200").unwrap();
+        assert_eq!(tm, Instant::from_gregorian(2024, 1, 1, 0, 0, 0.0).unwrap());
+        let tm = Instant::from_unix_seconds_str(" 1704067200.123456789 ").unwrap();
+        let expected = Instant::from_gregorian(2024, 1, 1, 0, 0, 0.123457).unwrap();
+        assert_eq!(tm, expected);
+        assert_eq!(
+            Instant::parse_epoch("-1.5", EpochKind::Unix).unwrap(),
+            Instant::from_gregorian(1969, 12, 31, 23, 59, 58.5).unwrap()
+        );
+
+        // GPS seconds count the leap seconds since 1980
+        let tm = Instant::from_gps_seconds_str("1388102418").unwrap();
+        assert_eq!(tm, Instant::from_gregorian(2024, 1, 1, 0, 0, 0.0).unwrap());
+        let tm = Instant::from_gps_seconds_str("604800.25").unwrap();
+        assert_eq!(tm.as_gps_week_and_sow(), (1, 0.25));
+
+        let tm = Instant::parse_epoch("+.5", EpochKind::J2000).unwrap();
+        assert_eq!(tm.raw, 500_000);
+        assert_eq!(
+            Instant::parse_epoch("-12.", EpochKind::J2000).unwrap().raw,
+            -12_000_000
+        );
+
+        for bad in [
+            "",
+            ".",
+            "-",
+            "12a",
+            "1e9",
+            "1.2.3",
+            "--1",
+            "1 2",
+            "99999999999999999999",
+        ] {
+            for kind in [EpochKind::Unix, EpochKind::GPS, EpochKind::J2000] {
+                assert!(matches!(
+                    Instant::parse_epoch(bad, kind),
+                    Err(SCError::InvalidTimeString)
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_string_with_scale() {
+        let tm = Instant::from_gregorian(2024, 6, 1, 23, 59, 0.0).unwrap();
+        assert_eq!(
+            tm.to_string_with_scale(TimeScale::UTC),
+            "2024-06-01T23:59:00.000000Z"
+        );
+        // TT = UTC + 37 s + 32.184 s
+        assert_eq!(
+            tm.to_string_with_scale(TimeScale::TT),
+            "2024-06-02T00:00:09.184000 TT"
+        );
+        assert_eq!(
+            tm.to_string_with_scale(TimeScale::GPS),
+            "2024-06-01T23:59:18.000000 GPS"
+        );
+        // J2000 is defined at noon TAI
+        assert_eq!(
+            Instant::J2000.to_string_with_scale(TimeScale::TAI),
+            "2000-01-01T12:00:00.000000 TAI"
+        );
+    }
+
+    #[test]
+    fn test_is_close() {
+        let tm: Instant = "2024-03-15T06:30:12.5Z".parse().unwrap();
+        let tol = Duration::from_seconds(1.0e-3);
+        assert!(tm.is_close(&(tm + Duration::from_microseconds(999)), tol));
+        assert!(tm.is_close(&(tm - Duration::from_microseconds(1000)), tol));
+        assert!(!tm.is_close(&(tm + Duration::from_microseconds(1001)), tol));
+        assert!(!tm.is_close(&(tm - Duration::from_seconds(1.0)), tol));
+        assert!(Instant::new(i64::MIN).is_close(&Instant::new(i64::MIN), tol));
+        assert!(!Instant::new(i64::MIN).is_close(&Instant::new(i64::MAX), tol));
+    }
+
+    #[test]
+    fn test_duration_ops() {
+        let before = Instant::from_gregorian(2016, 12, 31, 23, 59, 59.0).unwrap();
+        let after = Instant::from_gregorian(2017, 1, 1, 0, 0, 0.0).unwrap();
+        assert_eq!((after - before).as_seconds(), 2.0);
+        assert_eq!(before + Duration::from_seconds(2.0), after);
+        assert_eq!(after - Duration::from_seconds(2.0), before);
+    }
+
+    #[test]
+    fn test_ord_hash() {
+        let t0 = Instant::from_gregorian(2024, 1, 1, 0, 0, 0.0).unwrap();
+        let t1 = t0 + Duration::from_microseconds(1);
+        assert!(t0 < t1 && t1 > Instant::J2000);
+        let mut v = vec![t1, Instant::GPS_EPOCH, t0, t1];
+        v.sort();
+        v.dedup();
+        assert_eq!(v, vec![Instant::GPS_EPOCH, t0, t1]);
+        let set: std::collections::HashSet<Instant> = [t0, t1, t0].into_iter().collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_checked_duration_since() {
+        // A 2000-year baseline: 730,485 calendar days plus leap seconds
+        let a = Instant::from_gregorian(1000, 1, 1, 0, 0, 0.0).unwrap();
+        let b = Instant::from_gregorian(3000, 1, 1, 0, 0, 0.0).unwrap();
+        let d = b.checked_duration_since(&a).unwrap();
+        assert_eq!(d, b - a);
+        assert!((d.as_days() - 730_485.0).abs() < 1.0e-3);
+        assert_eq!(a.checked_duration_since(&b), Some(-d));
+
+        // Largest valid separations, and one microsecond past them
+        let lo = Instant::new(i64::MIN / 2);
+        let hi = Instant::new(i64::MAX / 2);
+        assert_eq!(hi.checked_duration_since(&lo).unwrap().usec, i64::MAX);
+        assert!(Instant::new(hi.raw + 1)
+            .checked_duration_since(&lo)
+            .is_none());
+        assert_eq!(
+            Instant::new(i64::MIN).checked_duration_since(&Instant::new(0)),
+            Some(Duration::from_microseconds(i64::MIN))
+        );
+        assert!(Instant::new(i64::MIN)
+            .checked_duration_since(&Instant::new(1))
+            .is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let tm = Instant::from_gregorian(2024, 3, 15, 6, 30, 12.5).unwrap();
+        let json = serde_json::to_string(&tm).unwrap();
+        assert_eq!(json, "\"2024-03-15T06:30:12.500000Z\"");
+        assert_eq!(serde_json::from_str::<Instant>(&json).unwrap(), tm);
+        assert!(serde_json::from_str::<Instant>("\"not a time\"").is_err());
+
+        let mut buf = Vec::new();
+        instant_raw::serialize(&tm, &mut serde_json::Serializer::new(&mut buf)).unwrap();
+        let json = String::from_utf8(buf).unwrap();
+        assert_eq!(json, tm.raw.to_string());
+        let mut de = serde_json::Deserializer::from_str(&json);
+        assert_eq!(instant_raw::deserialize(&mut de).unwrap(), tm);
+
+        assert_eq!(serde_json::to_string(&TimeScale::TT).unwrap(), "\"TT\"");
+        for scale in [
+            TimeScale::UTC,
+            TimeScale::TAI,
+            TimeScale::TT,
+            TimeScale::GPS,
+        ] {
+            let json = serde_json::to_string(&scale).unwrap();
+            assert_eq!(json, format!("\"{}\"", scale));
+            assert_eq!(serde_json::from_str::<TimeScale>(&json).unwrap(), scale);
+        }
+        assert!(serde_json::from_str::<TimeScale>("\"UT1\"").is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono() {
+        use chrono::{DateTime, TimeZone, Utc};
+
+        let dt = Utc.with_ymd_and_hms(2024, 3, 15, 6, 30, 12).unwrap()
+            + chrono::Duration::microseconds(500_000);
+        let tm = Instant::from(dt);
+        assert_eq!(
+            tm,
+            Instant::from_gregorian(2024, 3, 15, 6, 30, 12.5).unwrap()
+        );
+        assert_eq!(DateTime::<Utc>::try_from(tm).unwrap(), dt);
+
+        // chrono's leap second representation maps onto 23:59:60
+        let leap = chrono::NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_micro_opt(23, 59, 59, 1_250_000)
+            .unwrap()
+            .and_utc();
+        let tm = Instant::from(leap);
+        assert_eq!(tm.to_string(), "2016-12-31T23:59:60.250000Z");
+        assert_eq!(DateTime::<Utc>::try_from(tm).unwrap(), leap);
+        let after = Instant::from_gregorian(2017, 1, 1, 0, 0, 0.0).unwrap();
+        assert_eq!(after - tm, Duration::from_seconds(0.75));
     }
 }
@@ -1,4 +1,5 @@
 mod basemath;
+mod duration;
 mod instant;
 mod types;
 
@@ -26,17 +27,59 @@ pub use basemath::Matrix4;
 pub use basemath::Matrix5;
 pub use basemath::Matrix6;
 
+/// Symmetric matrices stored as their lower triangle
+pub use basemath::{
+    SymmetricMatrix, SymmetricMatrix2, SymmetricMatrix3, SymmetricMatrix4, SymmetricMatrix5,
+    SymmetricMatrix6,
+};
+
 /// Runge-Kutta 4th order method
 pub use basemath::rk4_integrate;
+pub use basemath::rk4_integrate_at;
 pub use basemath::rk4_integrate_inplace;
+pub use basemath::rk4_integrate_instant;
+pub use basemath::rk4_integrate_until;
+pub use basemath::rk4_integrate_with_stm;
+
+/// Runge-Kutta 8th order method
+pub use basemath::rk8_integrate;
+
+/// Explicit Runge-Kutta method from a Butcher tableau
+pub use basemath::{rk_integrate, ButcherTableau};
+
+/// Adaptive Runge-Kutta (Dormand-Prince 5(4)) method
+pub use basemath::{
+    rk45_integrate, CompositeState, IntegrationStats, ODEState, RKAdaptiveSettings,
+};
+
+/// Builder-style integrator configuration
+pub use basemath::{IntegrationMethod, Integrator};
 
 /// Math utilities
 pub use basemath::matrixutils;
 
+/// Angles and angle wrapping
+pub use basemath::{wrap_180, wrap_2pi, wrap_360, wrap_pi, Angle};
+
+/// Physical constants
+pub mod constants;
+/// Linear control analysis and design
+pub mod control;
 /// Filters (Kalman, etc)
 pub mod filters;
+/// Force and torque models
+pub mod forcemodels;
+/// Frame transformations
+pub mod frametransform;
+/// Two-body orbit utilities
+pub mod orbit;
 /// Library utilities
 pub mod utils;
 
 // Time utilities
+pub use duration::Duration;
+#[cfg(feature = "serde")]
+pub use instant::instant_raw;
+pub use instant::EpochKind;
 pub use instant::Instant;
+pub use instant::TimeScale;
@@ -0,0 +1,153 @@
+use crate::{Duration, Instant, SCError, SCResult, Vector3};
+
+/// Time and distance of closest approach between two trajectories
+///
+/// The separation is sampled on a uniform grid across the window, then
+/// each local minimum of the samples is refined by golden-section search
+/// over the bracketing grid intervals. The smallest refined minimum is
+/// returned.
+///
+/// # Arguments
+/// * `e1` - Position of the first object at a given instant, meters
+/// * `e2` - Position of the second object at a given instant, meters
+/// * `window` - Start and end of the search window
+/// * `step` - Grid spacing of the coarse scan; must be short compared to
+///   the time over which the separation changes direction (a fraction
+///   of an orbit period)
+///
+/// # Returns
+/// The instant of minimum separation and the separation in meters, or
+/// `SCError::InvalidInput` if the window end does not follow its start
+/// or if `step` is not positive
+///
+/// # Notes
+/// * A minimum narrower than the grid spacing may be missed; screening
+///   for conjunctions between LEO objects typically uses steps of tens
+///   of seconds
+/// * The refined time is resolved to the microsecond resolution of
+///   `Instant`
+///
+/// # Example
+/// ```
+/// use satctrl::orbit::closest_approach;
+/// use satctrl::{Duration, Instant, Vector3};
+/// let t0 = Instant::from_gregorian(2024, 1, 1, 0, 0, 0.0).unwrap();
+/// let e1 = |tm: &Instant| Vector3::from_vec([(*tm - t0).as_seconds() - 30.0, 0.0, 0.0]);
+/// let e2 = |_tm: &Instant| Vector3::from_vec([0.0, 5.0, 0.0]);
+/// let window = (t0, t0 + Duration::from_seconds(100.0));
+/// let (tca, dist) = closest_approach(e1, e2, window, Duration::from_seconds(10.0)).unwrap();
+/// assert!(((tca - t0).as_seconds() - 30.0).abs() < 1.0e-3);
+/// assert!((dist - 5.0).abs() < 1.0e-6);
+/// ```
+///
+pub fn closest_approach<F1, F2>(
+    e1: F1,
+    e2: F2,
+    window: (Instant, Instant),
+    step: Duration,
+) -> SCResult<(Instant, f64)>
+where
+    F1: Fn(&Instant) -> Vector3,
+    F2: Fn(&Instant) -> Vector3,
+{
+    let (start, end) = window;
+    if end.raw <= start.raw || step.usec <= 0 {
+        return Err(SCError::InvalidInput);
+    }
+
+    // Work in seconds since the start of the window
+    let span = (end - start).as_seconds();
+    let h = step.as_seconds();
+    let at = |s: f64| start + Duration::from_seconds(s);
+    let dist = |s: f64| {
+        let tm = at(s);
+        (e1(&tm) - e2(&tm)).norm()
+    };
+
+    let n = (span / h).ceil() as usize;
+    let times: Vec<f64> = (0..=n).map(|i| (i as f64 * h).min(span)).collect();
+    let d: Vec<f64> = times.iter().map(|s| dist(*s)).collect();
+
+    let mut best = (0.0, f64::INFINITY);
+    for i in 0..=n {
+        let lo = i.saturating_sub(1);
+        let hi = (i + 1).min(n);
+        if d[i] > d[lo] || d[i] > d[hi] {
+            continue;
+        }
+        let (s, di) = golden_section(&dist, times[lo], times[hi]);
+        let (s, di) = if d[i] < di { (times[i], d[i]) } else { (s, di) };
+        if di < best.1 {
+            best = (s, di);
+        }
+    }
+    Ok((at(best.0), best.1))
+}
+
+/// Golden-section search for the minimum of `f` on `[a, b]`, to 1 µs
+fn golden_section<F: Fn(f64) -> f64>(f: &F, mut a: f64, mut b: f64) -> (f64, f64) {
+    let invphi = (5.0_f64.sqrt() - 1.0) / 2.0;
+    let mut c = b - invphi * (b - a);
+    let mut d = a + invphi * (b - a);
+    let (mut fc, mut fd) = (f(c), f(d));
+    while b - a > 1.0e-6 {
+        if fc < fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = b - invphi * (b - a);
+            fc = f(c);
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + invphi * (b - a);
+            fd = f(d);
+        }
+    }
+    if fc < fd {
+        (c, fc)
+    } else {
+        (d, fd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closest_approach() {
+        // Crossing straight lines; the relative position is
+        // (7000 km, -5000 km, 200 m) + (-7, 5, 0) km/s * t, so the
+        // minimum is 200 m at t = 1000 s
+        let t0 = Instant::from_gregorian(2024, 6, 1, 12, 0, 0.0).unwrap();
+        let secs = |tm: &Instant| (*tm - t0).as_seconds();
+        let e1 = |tm: &Instant| Vector3::from_vec([-7.0e6 + 7.0e3 * secs(tm), 0.0, 0.0]);
+        let e2 = |tm: &Instant| Vector3::from_vec([0.0, -5.0e6 + 5.0e3 * secs(tm), 200.0]);
+        let window = (t0, t0 + Duration::from_hours(1.0));
+
+        let (tca, dist) = closest_approach(e1, e2, window, Duration::from_seconds(60.0)).unwrap();
+        assert!((secs(&tca) - 1000.0).abs() < 1.0e-3);
+        assert!((dist - 200.0).abs() < 1.0e-3);
+
+        // Step that does not divide the window, and a minimum at its start
+        let window = (
+            t0 + Duration::from_seconds(1500.0),
+            t0 + Duration::from_hours(1.0),
+        );
+        let (tca, dist) = closest_approach(e1, e2, window, Duration::from_seconds(70.0)).unwrap();
+        assert_eq!(tca.raw, window.0.raw);
+        let expected = (e1(&window.0) - e2(&window.0)).norm();
+        assert!((dist - expected).abs() < 1.0e-6);
+
+        assert!(matches!(
+            closest_approach(e1, e2, (t0, t0), Duration::from_seconds(60.0)),
+            Err(SCError::InvalidInput)
+        ));
+        assert!(matches!(
+            closest_approach(e1, e2, window, Duration::from_seconds(0.0)),
+            Err(SCError::InvalidInput)
+        ));
+    }
+}
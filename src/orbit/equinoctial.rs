@@ -0,0 +1,287 @@
+use super::eccentricity_vector;
+use crate::{wrap_2pi, SCError, SCResult, Vector3};
+
+/// Equinoctial orbital elements
+///
+/// A nonsingular set for elliptical orbits that remains well defined for
+/// circular and equatorial orbits, where the argument of periapsis and
+/// the right ascension of the ascending node are undefined. This is the
+/// natural parameterization for near-circular, near-equatorial orbits
+/// such as station-kept GEO.
+///
+/// In terms of the classical elements, with longitude of periapsis
+/// `ϖ = Ω + ω`:
+///
+/// * `h = e sin ϖ`, `k = e cos ϖ`
+/// * `p = tan(i/2) sin Ω`, `q = tan(i/2) cos Ω`
+/// * `lambda = M + ϖ`
+///
+/// # Notes
+/// * The prograde set is used; it is singular only for exactly
+///   retrograde equatorial orbits (i = 180°)
+/// * See Broucke and Cefola, "On the equinoctial orbit elements",
+///   Celestial Mechanics 5 (1972)
+///
+/// # Example
+/// ```
+/// use satctrl::orbit::EquinoctialElements;
+/// use satctrl::constants::MU_EARTH;
+/// use satctrl::Vector3;
+/// let a = 42164.0e3;
+/// let r = Vector3::from_vec([a, 0.0, 0.0]);
+/// let v = Vector3::from_vec([0.0, (MU_EARTH / a).sqrt(), 0.0]);
+/// let el = EquinoctialElements::from_cartesian(&r, &v, MU_EARTH).unwrap();
+/// assert!(el.eccentricity() < 1.0e-12 && el.inclination() < 1.0e-12);
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EquinoctialElements {
+    /// Semi-major axis, meters
+    pub a: f64,
+    /// Eccentricity vector component `e sin(Ω + ω)`
+    pub h: f64,
+    /// Eccentricity vector component `e cos(Ω + ω)`
+    pub k: f64,
+    /// Node vector component `tan(i/2) sin Ω`
+    pub p: f64,
+    /// Node vector component `tan(i/2) cos Ω`
+    pub q: f64,
+    /// Mean longitude `M + Ω + ω`, radians in [0, 2π)
+    pub lambda: f64,
+}
+
+impl EquinoctialElements {
+    /// Equinoctial frame basis vectors f̂ and ĝ, in the orbit plane
+    fn basis(&self) -> (Vector3, Vector3) {
+        let (p, q) = (self.p, self.q);
+        let s = 1.0 + p * p + q * q;
+        let f = Vector3::from_vec([1.0 - p * p + q * q, 2.0 * p * q, -2.0 * p]) / s;
+        let g = Vector3::from_vec([2.0 * p * q, 1.0 + p * p - q * q, 2.0 * q]) / s;
+        (f, g)
+    }
+
+    /// Eccentricity
+    pub fn eccentricity(&self) -> f64 {
+        self.h.hypot(self.k)
+    }
+
+    /// Inclination, radians
+    pub fn inclination(&self) -> f64 {
+        2.0 * self.p.hypot(self.q).atan()
+    }
+
+    /// Equinoctial elements from a Cartesian state
+    ///
+    /// # Arguments
+    /// * `r` - Position, meters
+    /// * `v` - Velocity, meters / second
+    /// * `mu` - Gravitational parameter, m^3/s^2
+    ///
+    /// # Returns
+    /// The elements, or `SCError::InvalidInput` if the orbit is not
+    /// elliptical, is retrograde equatorial, or the state is degenerate
+    ///
+    pub fn from_cartesian(r: &Vector3, v: &Vector3, mu: f64) -> SCResult<Self> {
+        let hvec = r.cross(v);
+        let hnorm = hvec.norm();
+        let a = 1.0 / (2.0 / r.norm() - v.normsq() / mu);
+        let w = hvec / hnorm;
+        if !(hnorm > 0.0 && a > 0.0 && a.is_finite()) || w[2] <= -1.0 + f64::EPSILON {
+            return Err(SCError::InvalidInput);
+        }
+        let mut el = Self {
+            a,
+            h: 0.0,
+            k: 0.0,
+            p: w[0] / (1.0 + w[2]),
+            q: -w[1] / (1.0 + w[2]),
+            lambda: 0.0,
+        };
+        let (f, g) = el.basis();
+        let e = eccentricity_vector(r, v, mu);
+        el.h = e.dot(&g);
+        el.k = e.dot(&f);
+        let (h, k) = (el.h, el.k);
+        if h * h + k * k >= 1.0 {
+            return Err(SCError::InvalidInput);
+        }
+
+        // Eccentric longitude from the in-plane position
+        let (x1, y1) = (r.dot(&f), r.dot(&g));
+        let root = (1.0 - h * h - k * k).sqrt();
+        let b = 1.0 / (1.0 + root);
+        let cos_f = k + ((1.0 - k * k * b) * x1 - h * k * b * y1) / (a * root);
+        let sin_f = h + ((1.0 - h * h * b) * y1 - h * k * b * x1) / (a * root);
+        let ecc_lon = sin_f.atan2(cos_f);
+        el.lambda = wrap_2pi(ecc_lon + h * cos_f - k * sin_f);
+        Ok(el)
+    }
+
+    /// Cartesian state from the equinoctial elements
+    ///
+    /// # Arguments
+    /// * `mu` - Gravitational parameter, m^3/s^2
+    ///
+    /// # Returns
+    /// Position (meters) and velocity (meters / second)
+    ///
+    pub fn to_cartesian(&self, mu: f64) -> (Vector3, Vector3) {
+        let (a, h, k) = (self.a, self.h, self.k);
+
+        // Kepler's equation in equinoctial form: λ = F + h cos F - k sin F
+        let mut ecc_lon = self.lambda;
+        for _ in 0..50 {
+            let (s, c) = ecc_lon.sin_cos();
+            let delta = (ecc_lon + h * c - k * s - self.lambda) / (1.0 - h * s - k * c);
+            ecc_lon -= delta;
+            if delta.abs() < 1.0e-15 {
+                break;
+            }
+        }
+        let (s, c) = ecc_lon.sin_cos();
+
+        let b = 1.0 / (1.0 + (1.0 - h * h - k * k).sqrt());
+        let x1 = a * ((1.0 - h * h * b) * c + h * k * b * s - k);
+        let y1 = a * (h * k * b * c + (1.0 - k * k * b) * s - h);
+        let rnorm = a * (1.0 - k * c - h * s);
+        let scale = a * a * (mu / a.powi(3)).sqrt() / rnorm;
+        let xd = scale * (h * k * b * c - (1.0 - h * h * b) * s);
+        let yd = scale * ((1.0 - k * k * b) * c - h * k * b * s);
+
+        let (f, g) = self.basis();
+        (f * x1 + g * y1, f * xd + g * yd)
+    }
+
+    /// Equinoctial elements from classical (Keplerian) elements
+    ///
+    /// # Arguments
+    /// * `a` - Semi-major axis, meters
+    /// * `e` - Eccentricity, in [0, 1)
+    /// * `i` - Inclination, radians, less than π
+    /// * `raan` - Right ascension of the ascending node, radians
+    /// * `argp` - Argument of periapsis, radians
+    /// * `mean_anomaly` - Mean anomaly, radians
+    ///
+    /// # Returns
+    /// The equivalent equinoctial elements
+    ///
+    pub fn from_classical(a: f64, e: f64, i: f64, raan: f64, argp: f64, mean_anomaly: f64) -> Self {
+        let lon_peri = raan + argp;
+        let t = (i / 2.0).tan();
+        Self {
+            a,
+            h: e * lon_peri.sin(),
+            k: e * lon_peri.cos(),
+            p: t * raan.sin(),
+            q: t * raan.cos(),
+            lambda: wrap_2pi(mean_anomaly + lon_peri),
+        }
+    }
+
+    /// Classical (Keplerian) elements from the equinoctial elements
+    ///
+    /// # Returns
+    /// The semi-major axis (meters), eccentricity, inclination, right
+    /// ascension of the ascending node, argument of periapsis, and mean
+    /// anomaly, with angles in radians and wrapped to [0, 2π). For
+    /// equatorial orbits the node is taken as zero, and for circular
+    /// orbits the argument of periapsis is taken as zero; the mean
+    /// anomaly absorbs the undefined angles
+    ///
+    pub fn to_classical(&self) -> (f64, f64, f64, f64, f64, f64) {
+        let e = self.eccentricity();
+        let i = self.inclination();
+        let raan = if self.p == 0.0 && self.q == 0.0 {
+            0.0
+        } else {
+            self.p.atan2(self.q)
+        };
+        let lon_peri = if e == 0.0 { raan } else { self.h.atan2(self.k) };
+        (
+            self.a,
+            e,
+            i,
+            wrap_2pi(raan),
+            wrap_2pi(lon_peri - raan),
+            wrap_2pi(self.lambda - lon_peri),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::MU_EARTH as MU;
+    use crate::wrap_pi;
+
+    fn assert_state_close(a: &(Vector3, Vector3), b: &(Vector3, Vector3)) {
+        assert!((a.0 - b.0).norm() < 1.0e-6 * a.0.norm());
+        assert!((a.1 - b.1).norm() < 1.0e-6 * a.1.norm());
+    }
+
+    #[test]
+    fn test_near_circular_equatorial() {
+        // GEO with eccentricity and inclination near zero, where the
+        // node and argument of periapsis are ill-defined
+        let a: f64 = 42164.0e3;
+        let vc = (MU / a).sqrt();
+        for (e, i) in [(0.0, 0.0), (1.0e-9, 0.0), (0.0, 1.0e-10), (2.0e-7, 3.0e-8)] {
+            let el = EquinoctialElements::from_classical(a, e, i, 1.3, 4.0, 0.7);
+            let state = el.to_cartesian(MU);
+            assert!((state.0.norm() - a).abs() <= 1.0e-3 + 1.01 * a * e);
+            assert!((state.1.norm() - vc).abs() <= 1.0e-6 + 1.01 * vc * e);
+
+            let el2 = EquinoctialElements::from_cartesian(&state.0, &state.1, MU).unwrap();
+            assert!((el2.a - a).abs() < 1.0e-4);
+            assert!((el2.h - el.h).abs() < 1.0e-12);
+            assert!((el2.k - el.k).abs() < 1.0e-12);
+            assert!((el2.p - el.p).abs() < 1.0e-12);
+            assert!((el2.q - el.q).abs() < 1.0e-12);
+            assert!(wrap_pi(el2.lambda - el.lambda).abs() < 1.0e-12);
+            assert_state_close(&el2.to_cartesian(MU), &state);
+        }
+
+        // Exactly circular and equatorial: λ is the true longitude
+        let r = Vector3::from_vec([0.0, a, 0.0]);
+        let v = Vector3::from_vec([-vc, 0.0, 0.0]);
+        let el = EquinoctialElements::from_cartesian(&r, &v, MU).unwrap();
+        assert!(el.eccentricity() < 1.0e-12);
+        assert_eq!((el.p, el.q), (0.0, 0.0));
+        assert!((el.lambda - std::f64::consts::FRAC_PI_2).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn test_equinoctial_round_trip() {
+        let (a, e, i, raan, argp, m) = (9000.0e3, 0.3, 1.1, 5.9, 0.4, 2.8);
+        let el = EquinoctialElements::from_classical(a, e, i, raan, argp, m);
+        assert!((el.eccentricity() - e).abs() < 1.0e-15);
+        assert!((el.inclination() - i).abs() < 1.0e-15);
+        let (a2, e2, i2, raan2, argp2, m2) = el.to_classical();
+        assert!((a2 - a).abs() < 1.0e-9 && (e2 - e).abs() < 1.0e-15);
+        assert!((i2 - i).abs() < 1.0e-14);
+        assert!(wrap_pi(raan2 - raan).abs() < 1.0e-14);
+        assert!(wrap_pi(argp2 - argp).abs() < 1.0e-14);
+        assert!(wrap_pi(m2 - m).abs() < 1.0e-14);
+
+        // Angular momentum and eccentricity vectors of the Cartesian state
+        let (r, v) = el.to_cartesian(MU);
+        let hvec = r.cross(&v);
+        assert!((hvec[2] / hvec.norm() - i.cos()).abs() < 1.0e-12);
+        assert!((eccentricity_vector(&r, &v, MU).norm() - e).abs() < 1.0e-12);
+        assert!((super::super::semi_major_axis(&r, &v, MU) - a).abs() < 1.0e-5);
+
+        let el2 = EquinoctialElements::from_cartesian(&r, &v, MU).unwrap();
+        assert!((el2.a - a).abs() < 1.0e-5);
+        assert!(wrap_pi(el2.lambda - el.lambda).abs() < 1.0e-12);
+        assert!((el2.h - el.h).abs() < 1.0e-12 && (el2.k - el.k).abs() < 1.0e-12);
+        assert!((el2.p - el.p).abs() < 1.0e-12 && (el2.q - el.q).abs() < 1.0e-12);
+
+        // Hyperbolic and retrograde equatorial states are rejected
+        let r = Vector3::from_vec([7.0e6, 0.0, 0.0]);
+        let vesc = (2.0 * MU / 7.0e6_f64).sqrt();
+        let v = Vector3::from_vec([0.0, 1.1 * vesc, 0.0]);
+        assert!(EquinoctialElements::from_cartesian(&r, &v, MU).is_err());
+        let v = Vector3::from_vec([0.0, -0.9 * vesc, 0.0]);
+        assert!(EquinoctialElements::from_cartesian(&r, &v, MU).is_err());
+    }
+}
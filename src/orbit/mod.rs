@@ -0,0 +1,12 @@
+mod conjunction;
+mod equinoctial;
+mod relative;
+mod twobody;
+
+pub use conjunction::closest_approach;
+pub use equinoctial::EquinoctialElements;
+pub use relative::{cw_propagate, cw_stm};
+
+pub use twobody::{
+    eccentricity_vector, semi_major_axis, specific_angular_momentum, specific_energy,
+};
@@ -0,0 +1,170 @@
+use crate::{Matrix6, Vector6};
+
+/// Clohessy-Wiltshire state transition matrix
+///
+/// Relative motion about a circular reference orbit, in the local
+/// Hill frame: x radial (away from the central body), y along-track
+/// (in the direction of motion), and z cross-track (along the orbit
+/// normal)
+///
+/// # Arguments
+/// * `n` - Mean motion of the reference orbit, radians / second
+/// * `dt` - Propagation time, seconds; may be negative
+///
+/// # Returns
+/// The 6x6 matrix mapping the relative state `[x, y, z, vx, vy, vz]` at
+/// the start of the interval to the relative state at its end
+///
+/// # Notes
+/// * See Vallado, "Fundamentals of Astrodynamics and Applications",
+///   Sec. 6.8
+/// * `n` = 0 is allowed, and gives free drift: position advances by
+///   velocity times `dt`
+///
+pub fn cw_stm(n: f64, dt: f64) -> Matrix6 {
+    let nt = n * dt;
+    let (s, c) = nt.sin_cos();
+    // sin(nt) / n and (1 - cos(nt)) / n, finite as n -> 0
+    let s_n = dt * sinc(nt);
+    let c_n = 0.5 * nt * dt * sinc(0.5 * nt).powi(2);
+    Matrix6::from_row_major_array([
+        [4.0 - 3.0 * c, 0.0, 0.0, s_n, 2.0 * c_n, 0.0],
+        [
+            6.0 * (s - nt),
+            1.0,
+            0.0,
+            -2.0 * c_n,
+            4.0 * s_n - 3.0 * dt,
+            0.0,
+        ],
+        [0.0, 0.0, c, 0.0, 0.0, s_n],
+        [3.0 * n * s, 0.0, 0.0, c, 2.0 * s, 0.0],
+        [-6.0 * n * (1.0 - c), 0.0, 0.0, -2.0 * s, 4.0 * c - 3.0, 0.0],
+        [0.0, 0.0, -n * s, 0.0, 0.0, c],
+    ])
+}
+
+/// sin(x) / x, using its series near zero
+fn sinc(x: f64) -> f64 {
+    if x.abs() < 1.0e-4 {
+        1.0 - x * x / 6.0
+    } else {
+        x.sin() / x
+    }
+}
+
+/// Propagate a relative state with the Clohessy-Wiltshire equations
+///
+/// The closed-form solution of the linearized relative motion about a
+/// circular reference orbit. Accurate while the separation is small
+/// compared to the orbit radius
+///
+/// # Arguments
+/// * `rel_state` - Relative position and velocity `[x, y, z, vx, vy, vz]`
+///   in the Hill frame (radial, along-track, cross-track); see `cw_stm`
+/// * `n` - Mean motion of the reference orbit, radians / second
+/// * `dt` - Propagation time, seconds; may be negative
+///
+/// # Returns
+/// The relative state after `dt`
+///
+/// # Example
+/// ```
+/// use satctrl::orbit::cw_propagate;
+/// use satctrl::Vector6;
+/// // 100 m below the reference drifts ahead by 12π * 100 m per orbit
+/// let n = 0.001;
+/// let s0 = Vector6::from_vec([-100.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+/// let s = cw_propagate(&s0, n, 2.0 * std::f64::consts::PI / n);
+/// assert!((s[1] - 1200.0 * std::f64::consts::PI).abs() < 1.0e-6);
+/// ```
+///
+pub fn cw_propagate(rel_state: &Vector6, n: f64, dt: f64) -> Vector6 {
+    cw_stm(n, dt) * *rel_state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rk4_integrate;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_cw_drift() {
+        let n = (crate::constants::MU_EARTH / 7.0e6_f64.powi(3)).sqrt();
+        let period = 2.0 * PI / n;
+
+        // A pure along-track offset is an equilibrium
+        let s0 = Vector6::from_vec([0.0, 250.0, 0.0, 0.0, 0.0, 0.0]);
+        for dt in [100.0, period / 3.0, 5.0 * period] {
+            assert!((cw_propagate(&s0, n, dt) - s0).norm() < 1.0e-9);
+        }
+
+        // A radial offset at rest in the Hill frame drifts along-track by
+        // 6 x0 (sin nt - nt), or -12π x0 per orbit
+        let x0 = 50.0;
+        let s0 = Vector6::from_vec([x0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        for dt in [0.37 * period, period, 3.0 * period] {
+            let s = cw_propagate(&s0, n, dt);
+            let nt = n * dt;
+            assert!((s[1] - 6.0 * x0 * (nt.sin() - nt)).abs() < 1.0e-8);
+            assert!((s[4] + 6.0 * n * x0 * (1.0 - nt.cos())).abs() < 1.0e-12);
+        }
+        let s = cw_propagate(&s0, n, period);
+        assert!((s[0] - x0).abs() < 1.0e-9);
+        assert!((s[1] + 12.0 * PI * x0).abs() < 1.0e-8);
+
+        // An along-track velocity of -2 n x0 removes the drift, giving a
+        // closed relative orbit
+        let s0 = Vector6::from_vec([x0, 0.0, 0.0, 0.0, -2.0 * n * x0, 0.0]);
+        let s = cw_propagate(&s0, n, 4.0 * period);
+        assert!((s - s0).norm() < 1.0e-8);
+    }
+
+    #[test]
+    fn test_cw_equations() {
+        // Agrees with numerical integration of the CW equations, and
+        // propagating backward returns to the start
+        let n = 0.0011;
+        let f = |_t: f64, s: &Vector6| {
+            Vector6::from_vec([
+                s[3],
+                s[4],
+                s[5],
+                3.0 * n * n * s[0] + 2.0 * n * s[4],
+                -2.0 * n * s[3],
+                -n * n * s[2],
+            ])
+        };
+        let s0 = Vector6::from_vec([12.0, -30.0, 5.0, 0.02, -0.01, 0.03]);
+        let mut s = s0;
+        for i in 0..600 {
+            s = rk4_integrate(f, i as f64 * 5.0, s, 5.0);
+        }
+        let cw = cw_propagate(&s0, n, 3000.0);
+        assert!((cw - s).norm() < 1.0e-8);
+        assert!((cw_propagate(&cw, n, -3000.0) - s0).norm() < 1.0e-9);
+    }
+
+    #[test]
+    fn test_cw_zero_mean_motion() {
+        // With no central body the relative motion is a straight line
+        let s0 = Vector6::from_vec([12.0, -30.0, 5.0, 0.02, -0.01, 0.03]);
+        let s = cw_propagate(&s0, 0.0, 100.0);
+        let expected = Vector6::from_vec([14.0, -31.0, 8.0, 0.02, -0.01, 0.03]);
+        assert!((s - expected).norm() < 1.0e-12);
+
+        // The matrix is continuous through n = 0, differing from free drift
+        // by at most n dt², and across the switch to the series
+        let dt = 100.0;
+        let phi0 = cw_stm(0.0, dt);
+        assert!(phi0.all(|x| x.is_finite()));
+        for n in [1.0e-12, 1.0e-7, 0.99e-6, 1.01e-6, -1.0e-7] {
+            let err = cw_stm(n, dt) - phi0;
+            assert!(err.all(|x| x.abs() <= 1.01 * n.abs() * dt * dt));
+        }
+        let n = 1.0e-6 * (1.0 + 1.0e-9);
+        let err = cw_stm(n, dt) - cw_stm(1.0e-6 * (1.0 - 1.0e-9), dt);
+        assert!(err.all(|x| x.abs() < 1.0e-10));
+    }
+}
@@ -0,0 +1,117 @@
+use crate::Vector3;
+
+/// Specific orbital energy of a two-body state
+///
+/// # Arguments
+/// * `r` - Position, meters
+/// * `v` - Velocity, meters / second
+/// * `mu` - Gravitational parameter, m^3/s^2
+///
+/// # Returns
+/// The vis-viva energy `v²/2 - mu/r` in J/kg; negative for bound orbits
+///
+/// # Example
+/// ```
+/// use satctrl::orbit::specific_energy;
+/// use satctrl::Vector3;
+/// let mu = 3.986004418e14;
+/// let r = Vector3::from_vec([7.0e6, 0.0, 0.0]);
+/// let v = Vector3::from_vec([0.0, (mu / 7.0e6_f64).sqrt(), 0.0]);
+/// assert!((specific_energy(&r, &v, mu) + mu / 14.0e6).abs() < 1.0e-6);
+/// ```
+///
+pub fn specific_energy(r: &Vector3, v: &Vector3, mu: f64) -> f64 {
+    0.5 * v.normsq() - mu / r.norm()
+}
+
+/// Specific angular momentum of a two-body state
+///
+/// # Arguments
+/// * `r` - Position, meters
+/// * `v` - Velocity, meters / second
+///
+/// # Returns
+/// The angular momentum vector `r × v`, m^2/s
+///
+pub fn specific_angular_momentum(r: &Vector3, v: &Vector3) -> Vector3 {
+    r.cross(v)
+}
+
+/// Semi-major axis of a two-body state
+///
+/// # Arguments
+/// * `r` - Position, meters
+/// * `v` - Velocity, meters / second
+/// * `mu` - Gravitational parameter, m^3/s^2
+///
+/// # Returns
+/// The semi-major axis `-mu / (2E)` in meters; negative for hyperbolic
+/// orbits and infinite for parabolic orbits
+///
+pub fn semi_major_axis(r: &Vector3, v: &Vector3, mu: f64) -> f64 {
+    -mu / (2.0 * specific_energy(r, v, mu))
+}
+
+/// Eccentricity vector of a two-body state
+///
+/// # Arguments
+/// * `r` - Position, meters
+/// * `v` - Velocity, meters / second
+/// * `mu` - Gravitational parameter, m^3/s^2
+///
+/// # Returns
+/// The eccentricity vector, pointing toward periapsis with magnitude
+/// equal to the eccentricity
+///
+pub fn eccentricity_vector(r: &Vector3, v: &Vector3, mu: f64) -> Vector3 {
+    ((v.normsq() - mu / r.norm()) * r - r.dot(v) * v) / mu
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::MU_EARTH as MU;
+    use crate::{rk4_integrate, Vector6};
+
+    fn split(s: &Vector6) -> (Vector3, Vector3) {
+        (
+            Vector3::from_vec([s[0], s[1], s[2]]),
+            Vector3::from_vec([s[3], s[4], s[5]]),
+        )
+    }
+
+    #[test]
+    fn test_circular() {
+        let a = 7.0e6;
+        let r = Vector3::from_vec([a, 0.0, 0.0]);
+        let v = Vector3::from_vec([0.0, (MU / a).sqrt(), 0.0]);
+        assert!((specific_energy(&r, &v, MU) + MU / (2.0 * a)).abs() < 1.0e-6);
+        assert!((semi_major_axis(&r, &v, MU) - a).abs() < 1.0e-6);
+        assert!(eccentricity_vector(&r, &v, MU).norm() < 1.0e-12);
+        let h = specific_angular_momentum(&r, &v);
+        assert!((h.norm() - (MU * a).sqrt()).abs() < 1.0e-3);
+    }
+
+    #[test]
+    fn test_conserved_over_arc() {
+        let r = Vector3::from_vec([7.0e6, 1.0e6, -5.0e5]);
+        let v = Vector3::from_vec([-500.0, 7.2e3, 1.5e3]);
+        let e0 = specific_energy(&r, &v, MU);
+        let h0 = specific_angular_momentum(&r, &v);
+        let ecc0 = eccentricity_vector(&r, &v, MU);
+
+        let f = |_t: f64, s: &Vector6| {
+            let (r, v) = split(s);
+            let a = -MU / r.norm().powi(3) * r;
+            Vector6::from_vec([v[0], v[1], v[2], a[0], a[1], a[2]])
+        };
+        let mut s = Vector6::from_vec([r[0], r[1], r[2], v[0], v[1], v[2]]);
+        for i in 0..600 {
+            s = rk4_integrate(f, i as f64 * 10.0, s, 10.0);
+        }
+        let (r, v) = split(&s);
+        assert!(((specific_energy(&r, &v, MU) - e0) / e0).abs() < 1.0e-8);
+        assert!((specific_angular_momentum(&r, &v) - h0).norm() / h0.norm() < 1.0e-8);
+        assert!((eccentricity_vector(&r, &v, MU) - ecc0).norm() < 1.0e-8);
+    }
+}
@@ -1,5 +1,6 @@
 /// Encapsulate all the possible errors that can occur in the library
 ///
+#[derive(Debug)]
 pub enum SCError {
     /// Error message
     Message(String),
@@ -11,6 +12,12 @@ pub enum SCError {
     MatrixIsSingular,
     VectorNormIsZero,
     NonPositiveDefiniteMatrix,
+    /// Iterative algorithm failed to converge
+    NotConverged,
+    /// Adaptive integrator could not meet its tolerance at the minimum step size
+    StepSizeTooSmall,
+    /// String could not be parsed as a time
+    InvalidTimeString,
 }
 
 pub type SCResult<T> = Result<T, SCError>;
@@ -1,5 +1,11 @@
-/// This module contains utility functions that are used throughout the project.
-///
+//! This module contains utility functions that are used throughout the project.
+//!
+
+mod polyfit;
+/// Seeded pseudo-random number generation
+pub mod rand;
+
+pub use polyfit::{polyfit, polyval};
 
 /// Returns the git hash of the current commit.
 ///
@@ -0,0 +1,125 @@
+use crate::{SCError, SCResult};
+
+/// Least-squares polynomial fit
+///
+/// Builds the Vandermonde matrix of `x` and solves the least-squares
+/// problem with a Householder QR decomposition, which avoids squaring
+/// the condition number as the normal equations would
+///
+/// # Arguments
+/// * `x` - The sample locations
+/// * `y` - The sample values, same length as `x`
+/// * `degree` - The polynomial degree
+///
+/// # Returns
+/// The `degree + 1` coefficients in ascending order of power, i.e.
+/// `y ≈ c[0] + c[1] x + c[2] x² + ...`. Returns `SCError::InvalidInput`
+/// if the lengths differ or there are fewer points than coefficients, and
+/// `SCError::MatrixIsSingular` if the sample locations do not determine
+/// the fit (e.g. repeated `x` values)
+///
+/// # Example
+/// ```
+/// use satctrl::utils::{polyfit, polyval};
+/// let x = [0.0, 1.0, 2.0, 3.0];
+/// let y = [1.0, 3.0, 5.0, 7.0];
+/// let c = polyfit(&x, &y, 1).unwrap();
+/// assert!((polyval(&c, 4.0) - 9.0).abs() < 1.0e-12);
+/// ```
+///
+pub fn polyfit(x: &[f64], y: &[f64], degree: usize) -> SCResult<Vec<f64>> {
+    let m = x.len();
+    let n = degree + 1;
+    if y.len() != m || m < n {
+        return Err(SCError::InvalidInput);
+    }
+
+    // Vandermonde matrix, stored by column
+    let mut a: Vec<Vec<f64>> = (0..n)
+        .map(|j| x.iter().map(|xi| xi.powi(j as i32)).collect())
+        .collect();
+    let mut b = y.to_vec();
+
+    // Householder QR, applying each reflection to b as we go
+    for k in 0..n {
+        let norm = a[k][k..].iter().map(|v| v * v).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            return Err(SCError::MatrixIsSingular);
+        }
+        let alpha = if a[k][k] > 0.0 { -norm } else { norm };
+        let mut v = a[k][k..].to_vec();
+        v[0] -= alpha;
+        let vnorm2 = v.iter().map(|vi| vi * vi).sum::<f64>();
+        for col in a.iter_mut().skip(k).chain(std::iter::once(&mut b)) {
+            let dot = v.iter().zip(&col[k..]).map(|(vi, ci)| vi * ci).sum::<f64>();
+            let s = 2.0 * dot / vnorm2;
+            for (ci, vi) in col[k..].iter_mut().zip(&v) {
+                *ci -= s * vi;
+            }
+        }
+    }
+
+    // Back substitution with the upper-triangular R
+    let scale = a.iter().map(|col| col[0].abs()).fold(1.0, f64::max);
+    let mut c = vec![0.0; n];
+    for i in (0..n).rev() {
+        if a[i][i].abs() <= f64::EPSILON * m as f64 * scale {
+            return Err(SCError::MatrixIsSingular);
+        }
+        let mut sum = b[i];
+        for j in i + 1..n {
+            sum -= a[j][i] * c[j];
+        }
+        c[i] = sum / a[i][i];
+    }
+    Ok(c)
+}
+
+/// Evaluate a polynomial with Horner's method
+///
+/// # Arguments
+/// * `coeffs` - Coefficients in ascending order of power, as returned by `polyfit`
+/// * `x` - Where to evaluate the polynomial
+///
+/// # Returns
+/// `c[0] + c[1] x + c[2] x² + ...`
+///
+pub fn polyval(coeffs: &[f64], x: f64) -> f64 {
+    coeffs.iter().rev().fold(0.0, |acc, c| acc * x + c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polyfit_quadratic() {
+        let x: Vec<f64> = (0..10).map(|i| i as f64 * 0.5 - 2.0).collect();
+        let y: Vec<f64> = x.iter().map(|x| 3.0 - 2.0 * x + 0.5 * x * x).collect();
+        let c = polyfit(&x, &y, 2).unwrap();
+        assert_eq!(c.len(), 3);
+        assert!((c[0] - 3.0).abs() < 1.0e-12);
+        assert!((c[1] + 2.0).abs() < 1.0e-12);
+        assert!((c[2] - 0.5).abs() < 1.0e-12);
+        assert!((polyval(&c, 10.0) - 33.0).abs() < 1.0e-10);
+
+        // Over-fitting with a higher degree leaves the extra terms ~zero
+        let c = polyfit(&x, &y, 4).unwrap();
+        assert!(c[3].abs() < 1.0e-10 && c[4].abs() < 1.0e-10);
+    }
+
+    #[test]
+    fn test_polyfit_degenerate() {
+        let x = [0.0, 1.0];
+        let y = [1.0, 2.0];
+        assert!(matches!(polyfit(&x, &y, 2), Err(SCError::InvalidInput)));
+        assert!(matches!(
+            polyfit(&x, &y[..1], 1),
+            Err(SCError::InvalidInput)
+        ));
+        assert!(matches!(
+            polyfit(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0], 1),
+            Err(SCError::MatrixIsSingular)
+        ));
+    }
+}